@@ -0,0 +1,85 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Bencher, BenchmarkId, Criterion};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    widgets::{Row, StatefulWidget, Table, TableState},
+};
+
+/// Benchmark for rendering a table.
+/// It compares rendering all the visible rows with only rendering a single changed row.
+pub fn table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table");
+
+    for row_count in [64, 2048, 16384] {
+        let rows: Vec<Row> = (0..row_count)
+            .map(|i| Row::new((0..4).map(|column| format!("row {i} column {column}"))))
+            .collect();
+        let table = Table::new(rows, [Constraint::Ratio(1, 4); 4]);
+
+        // Render all the visible rows
+        group.bench_with_input(BenchmarkId::new("render", row_count), &table, |b, table| {
+            render_stateful(b, table, |_| {})
+        });
+
+        // Render only the first visible row
+        group.bench_with_input(
+            BenchmarkId::new("render_incremental", row_count),
+            &table.clone().incremental(true),
+            |b, table| render_stateful(b, table, |state| state.mark_row_dirty(0)),
+        );
+    }
+
+    // Render a wide table at the same width, reusing the widths of the columns of the previous
+    // frame, and at an alternating width, computing them on every frame
+    let rows: Vec<Row> = (0..64)
+        .map(|i| Row::new((0..50).map(|column| format!("{i}:{column}"))))
+        .collect();
+    let table = Table::new(rows, [Constraint::Min(2); 50]);
+    group.bench_with_input(
+        BenchmarkId::new("render_wide", "same_width"),
+        &table,
+        |b, table| render_resized(b, table, |_| 200),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("render_wide", "alternating_width"),
+        &table,
+        |b, table| render_resized(b, table, |frame| 199 + (frame % 2) as u16),
+    );
+
+    group.finish();
+}
+
+/// render the table with a state into an area whose width depends on the index of the frame
+fn render_resized(bencher: &mut Bencher, table: &Table, width: impl Fn(usize) -> u16) {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+    let mut state = TableState::default();
+    let mut frame = 0;
+    bencher.iter_batched(
+        || table.to_owned(),
+        |bench_table| {
+            frame += 1;
+            let area = Rect::new(0, 0, width(frame), 50);
+            StatefulWidget::render(bench_table, area, &mut buffer, &mut state);
+        },
+        BatchSize::LargeInput,
+    )
+}
+
+/// render the table into a common size buffer with a state, updating the state before each render
+fn render_stateful(bencher: &mut Bencher, table: &Table, update: impl Fn(&mut TableState)) {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+    let mut state = TableState::default();
+    // We use `iter_batched` to clone the value in the setup function.
+    // See https://github.com/ratatui-org/ratatui/pull/377.
+    bencher.iter_batched(
+        || table.to_owned(),
+        |bench_table| {
+            update(&mut state);
+            StatefulWidget::render(bench_table, buffer.area, &mut buffer, &mut state);
+        },
+        BatchSize::LargeInput,
+    )
+}
+
+criterion_group!(benches, table);
+criterion_main!(benches);
@@ -275,6 +275,54 @@ pub trait Backend {
         }
     }
 
+    /// Scrolls the content of a region of the terminal screen by the given number of lines
+    ///
+    /// A positive number of `lines` scrolls the content up, and a negative number scrolls it down.
+    /// The content scrolled out of the `area` is discarded, and the lines scrolled into it are
+    /// cleared. The content outside of the `area` is not modified.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error. Backends relying on the scroll region escape sequences of
+    /// the terminal only support areas spanning the full width of the screen.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, backend::TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// // scroll the lines 5 to 14 up by 2 lines
+    /// backend.scroll_region(Rect::new(0, 5, 80, 10), 2)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the region could not be scrolled, or if scrolling the
+    /// `area` is not supported by the backend.
+    fn scroll_region(&mut self, _area: Rect, _lines: i16) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "scroll_region not supported with this backend",
+        ))
+    }
+
+    /// Set the title of the terminal window.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::backend::{Backend, TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// backend.set_title("My application")?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    fn set_title(&mut self, _title: &str) -> io::Result<()> {
+        Ok(())
+    }
+
     /// Get the size of the terminal screen in columns/rows as a [`Rect`].
     ///
     /// The returned [`Rect`] contains the width and height of the terminal screen.
@@ -7,13 +7,13 @@ use std::io::{self, Write};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
+    cursor::{Hide, MoveTo, RestorePosition, SavePosition, Show},
     execute, queue,
     style::{
         Attribute as CAttribute, Attributes as CAttributes, Color as CColor, ContentStyle, Print,
         SetAttribute, SetBackgroundColor, SetForegroundColor,
     },
-    terminal::{self, Clear},
+    terminal::{self, Clear, ScrollDown, ScrollUp, SetTitle},
 };
 
 use crate::{
@@ -223,6 +223,37 @@ where
         self.writer.flush()
     }
 
+    /// Scrolls the region using the scroll region escape sequences of the terminal.
+    ///
+    /// Only areas spanning the full width of the screen are supported.
+    fn scroll_region(&mut self, area: Rect, lines: i16) -> io::Result<()> {
+        if area.x != 0 || area.width != self.size()?.width {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "scroll_region only supports full width areas with this backend",
+            ));
+        }
+        if area.height == 0 || lines == 0 {
+            return Ok(());
+        }
+        // setting the scroll region moves the cursor to the top left corner of the screen
+        queue!(
+            self.writer,
+            SavePosition,
+            Print(format!("\x1b[{};{}r", area.top() + 1, area.bottom()))
+        )?;
+        if lines > 0 {
+            queue!(self.writer, ScrollUp(lines.unsigned_abs()))?;
+        } else {
+            queue!(self.writer, ScrollDown(lines.unsigned_abs()))?;
+        }
+        execute!(self.writer, Print("\x1b[r"), RestorePosition)
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        execute!(self.writer, SetTitle(title))
+    }
+
     fn size(&self) -> io::Result<Rect> {
         let (width, height) = terminal::size()?;
         Ok(Rect::new(0, 0, width, height))
@@ -119,6 +119,35 @@ where
         self.writer.flush()
     }
 
+    /// Scrolls the region using the scroll region escape sequences of the terminal.
+    ///
+    /// Only areas spanning the full width of the screen are supported.
+    fn scroll_region(&mut self, area: Rect, lines: i16) -> io::Result<()> {
+        if area.x != 0 || area.width != self.size()?.width {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "scroll_region only supports full width areas with this backend",
+            ));
+        }
+        if area.height == 0 || lines == 0 {
+            return Ok(());
+        }
+        // setting the scroll region moves the cursor to the top left corner of the screen
+        write!(
+            self.writer,
+            "\x1b7\x1b[{};{}r",
+            area.top() + 1,
+            area.bottom()
+        )?;
+        if lines > 0 {
+            write!(self.writer, "\x1b[{}S", lines.unsigned_abs())?;
+        } else {
+            write!(self.writer, "\x1b[{}T", lines.unsigned_abs())?;
+        }
+        write!(self.writer, "\x1b[r\x1b8")?;
+        self.writer.flush()
+    }
+
     fn append_lines(&mut self, n: u16) -> io::Result<()> {
         for _ in 0..n {
             writeln!(self.writer)?;
@@ -145,6 +174,13 @@ where
         self.writer.flush()
     }
 
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        // termion has no command for this, so write the OSC 0 sequence (set icon name and window
+        // title) directly
+        write!(self.writer, "\x1b]0;{title}\x07")?;
+        self.writer.flush()
+    }
+
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
@@ -40,6 +40,8 @@ pub struct TestBackend {
     height: u16,
     cursor: bool,
     pos: (u16, u16),
+    title: Option<String>,
+    last_drawn: Vec<(u16, u16)>,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -80,6 +82,8 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             cursor: false,
             pos: (0, 0),
+            title: None,
+            last_drawn: vec![],
         }
     }
 
@@ -88,6 +92,31 @@ impl TestBackend {
         &self.buffer
     }
 
+    /// Returns the last title set with [`Backend::set_title`], if any.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Returns the positions of the cells written by the last call to [`Backend::draw`], in the
+    /// order they were written.
+    ///
+    /// This can be used to check which cells a flush of the terminal sent to the backend.
+    pub fn last_drawn_cells(&self) -> &[(u16, u16)] {
+        &self.last_drawn
+    }
+
+    /// Returns the last cursor position set with [`Backend::set_cursor`].
+    pub fn cursor(&self) -> (u16, u16) {
+        self.pos
+    }
+
+    /// Returns `true` if the cursor is hidden.
+    ///
+    /// The cursor is hidden until [`Backend::show_cursor`] is called.
+    pub fn cursor_hidden(&self) -> bool {
+        !self.cursor
+    }
+
     /// Resizes the TestBackend to the specified width and height.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
@@ -148,9 +177,11 @@ impl Backend for TestBackend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        self.last_drawn.clear();
         for (x, y, c) in content {
             let cell = self.buffer.get_mut(x, y);
             *cell = c.clone();
+            self.last_drawn.push((x, y));
         }
         Ok(())
     }
@@ -244,6 +275,38 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    /// Shifts the cells of the area in the buffer by the given number of lines.
+    ///
+    /// Unlike terminals, any area of the buffer can be scrolled, not only full width lines.
+    fn scroll_region(&mut self, area: Rect, lines: i16) -> io::Result<()> {
+        let area = area.intersection(self.buffer.area);
+        let shift = lines.unsigned_abs().min(area.height);
+        let rows: Vec<u16> = if lines > 0 {
+            (area.top()..area.bottom()).collect()
+        } else {
+            (area.top()..area.bottom()).rev().collect()
+        };
+        for y in rows {
+            let source_y = if lines > 0 {
+                Some(y + shift).filter(|&y| y < area.bottom())
+            } else {
+                y.checked_sub(shift).filter(|&y| y >= area.top())
+            };
+            for x in area.left()..area.right() {
+                let cell = source_y.map_or_else(Cell::default, |source_y| {
+                    self.buffer.get(x, source_y).clone()
+                });
+                *self.buffer.get_mut(x, y) = cell;
+            }
+        }
+        Ok(())
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.title = Some(title.to_string());
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         Ok(Rect::new(0, 0, self.width, self.height))
     }
@@ -279,6 +342,8 @@ mod tests {
                 buffer: Buffer::with_lines(vec!["          "; 2]),
                 cursor: false,
                 pos: (0, 0),
+                title: None,
+                last_drawn: vec![],
             }
         );
     }
@@ -308,6 +373,15 @@ mod tests {
         assert_eq!(backend.buffer(), &Buffer::with_lines(vec!["          "; 2]));
     }
 
+    #[test]
+    fn set_title() {
+        let mut backend = TestBackend::new(10, 2);
+        assert_eq!(backend.title(), None);
+        backend.set_title("first").unwrap();
+        backend.set_title("second").unwrap();
+        assert_eq!(backend.title(), Some("second"));
+    }
+
     #[test]
     fn resize() {
         let mut backend = TestBackend::new(10, 2);
@@ -346,6 +420,19 @@ mod tests {
         backend.assert_buffer(&Buffer::with_lines(vec!["a         "; 2]));
     }
 
+    #[test]
+    fn last_drawn_cells() {
+        let mut backend = TestBackend::new(10, 2);
+        assert!(backend.last_drawn_cells().is_empty());
+        let cell = Cell::default();
+        backend
+            .draw([(0, 0, &cell), (3, 1, &cell)].into_iter())
+            .unwrap();
+        assert_eq!(backend.last_drawn_cells(), [(0, 0), (3, 1)]);
+        backend.draw([(1, 1, &cell)].into_iter()).unwrap();
+        assert_eq!(backend.last_drawn_cells(), [(1, 1)]);
+    }
+
     #[test]
     fn hide_cursor() {
         let mut backend = TestBackend::new(10, 2);
@@ -360,6 +447,24 @@ mod tests {
         assert!(backend.cursor);
     }
 
+    #[test]
+    fn cursor() {
+        let mut backend = TestBackend::new(10, 10);
+        assert_eq!(backend.cursor(), (0, 0));
+        backend.set_cursor(3, 4).unwrap();
+        assert_eq!(backend.cursor(), (3, 4));
+    }
+
+    #[test]
+    fn cursor_hidden() {
+        let mut backend = TestBackend::new(10, 2);
+        assert!(backend.cursor_hidden());
+        backend.show_cursor().unwrap();
+        assert!(!backend.cursor_hidden());
+        backend.hide_cursor().unwrap();
+        assert!(backend.cursor_hidden());
+    }
+
     #[test]
     fn get_cursor() {
         let mut backend = TestBackend::new(10, 2);
@@ -389,6 +494,34 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn scroll_region_up() {
+        let mut backend = TestBackend::new(6, 5);
+        backend.buffer = Buffer::with_lines(vec!["aaaaaa", "bbbbbb", "cccccc", "dddddd", "eeeeee"]);
+        backend.scroll_region(Rect::new(1, 1, 4, 3), 2).unwrap();
+        backend.assert_buffer(&Buffer::with_lines(vec![
+            "aaaaaa", "bddddb", "c    c", "d    d", "eeeeee",
+        ]));
+    }
+
+    #[test]
+    fn scroll_region_down() {
+        let mut backend = TestBackend::new(6, 5);
+        backend.buffer = Buffer::with_lines(vec!["aaaaaa", "bbbbbb", "cccccc", "dddddd", "eeeeee"]);
+        backend.scroll_region(Rect::new(0, 1, 6, 3), -1).unwrap();
+        backend.assert_buffer(&Buffer::with_lines(vec![
+            "aaaaaa", "      ", "bbbbbb", "cccccc", "eeeeee",
+        ]));
+    }
+
+    #[test]
+    fn scroll_region_more_lines_than_area() {
+        let mut backend = TestBackend::new(3, 3);
+        backend.buffer = Buffer::with_lines(vec!["aaa", "bbb", "ccc"]);
+        backend.scroll_region(Rect::new(0, 0, 3, 2), 5).unwrap();
+        backend.assert_buffer(&Buffer::with_lines(vec!["   ", "   ", "ccc"]));
+    }
+
     #[test]
     fn clear_region_all() {
         let mut backend = TestBackend::new(10, 5);
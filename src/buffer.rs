@@ -432,6 +432,40 @@ impl Buffer {
         self.area = area;
     }
 
+    /// Copy the cells of `src_area` in the `src` buffer into this buffer, with the top left
+    /// corner of `src_area` placed at the `(x, y)` position of `dest`.
+    ///
+    /// The copied region is clipped to the area of both buffers. This makes it possible to render
+    /// a widget once into a larger offscreen buffer and then blit a shifting window of it into
+    /// the visible area, e.g. to scroll smoothly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, buffer::Buffer};
+    /// let src = Buffer::with_lines(vec!["abcd", "efgh"]);
+    /// let mut dest = Buffer::empty(Rect::new(0, 0, 3, 1));
+    /// dest.blit_from(&src, Rect::new(1, 1, 3, 1), (0, 0));
+    /// assert_eq!(dest, Buffer::with_lines(vec!["fgh"]));
+    /// ```
+    pub fn blit_from(&mut self, src: &Buffer, src_area: Rect, dest: (u16, u16)) {
+        let src_area = src.area.intersection(src_area);
+        let (dest_x, dest_y) = dest;
+        let dest_area = self.area.intersection(Rect {
+            x: dest_x,
+            y: dest_y,
+            width: src_area.width,
+            height: src_area.height,
+        });
+        for y in dest_area.top()..dest_area.bottom() {
+            for x in dest_area.left()..dest_area.right() {
+                let src_x = src_area.x + (x - dest_x);
+                let src_y = src_area.y + (y - dest_y);
+                *self.get_mut(x, y) = src.get(src_x, src_y).clone();
+            }
+        }
+    }
+
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
@@ -483,6 +517,52 @@ impl Buffer {
         }
         updates
     }
+
+    /// Builds a minimal sequence of coordinates and Cells necessary to update the cells of `area`
+    /// from self to other.
+    ///
+    /// This is the same as [`Buffer::diff`], except that only the cells inside `area` are compared,
+    /// so that a part of the screen can be updated on its own. The cells outside of `area` are not
+    /// part of the updates, even when they changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{buffer::Cell, prelude::*};
+    /// let previous = Buffer::with_lines(vec!["abc", "def"]);
+    /// let next = Buffer::with_lines(vec!["xbc", "dey"]);
+    /// let diff = previous.diff_area(&next, Rect::new(0, 0, 3, 1));
+    /// assert_eq!(diff.len(), 1);
+    /// assert_eq!((diff[0].0, diff[0].1), (0, 0));
+    /// ```
+    pub fn diff_area<'a>(&self, other: &'a Buffer, area: Rect) -> Vec<(u16, u16, &'a Cell)> {
+        let area = self.area.intersection(other.area).intersection(area);
+        let mut updates: Vec<(u16, u16, &Cell)> = vec![];
+        for y in area.top()..area.bottom() {
+            let mut invalidated: usize = 0;
+            let mut to_skip: usize = 0;
+            // a multi-width character right before the area may overlap its first cell
+            let start = area.left().saturating_sub(1).max(self.area.left());
+            for x in start..area.right() {
+                let current = other.get(x, y);
+                let previous = self.get(x, y);
+                if x >= area.left()
+                    && !current.skip
+                    && (current != previous || invalidated > 0)
+                    && to_skip == 0
+                {
+                    updates.push((x, y, current));
+                }
+
+                to_skip = current.symbol().width().saturating_sub(1);
+
+                let affected_width =
+                    std::cmp::max(current.symbol().width(), previous.symbol().width());
+                invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+            }
+        }
+        updates
+    }
 }
 
 /// Assert that two buffers are equal by comparing their areas and content.
@@ -928,6 +1008,33 @@ mod tests {
         assert_eq!(diff, vec![(0, 0, &cell("4"))],);
     }
 
+    #[test]
+    #[rustfmt::skip]
+    fn buffer_diffing_area() {
+        let prev = Buffer::with_lines(vec![
+            "abcde",
+            "fghij",
+        ]);
+        let next = Buffer::with_lines(vec![
+            "ABcDE",
+            "fgHij",
+        ]);
+        let diff = prev.diff_area(&next, Rect::new(1, 0, 2, 2));
+        assert_eq!(diff, vec![(1, 0, &cell("B")), (2, 1, &cell("H"))]);
+        // the area is clamped to the buffers
+        let diff = prev.diff_area(&next, Rect::new(4, 1, 10, 10));
+        assert_eq!(diff, vec![]);
+    }
+
+    #[test]
+    fn buffer_diffing_area_multi_width() {
+        let prev = Buffer::with_lines(vec!["┌称号─┐"]);
+        let next = Buffer::with_lines(vec!["┌─称号┐"]);
+        // the cell hidden by "称" is skipped, as in a full diff
+        let diff = prev.diff_area(&next, Rect::new(2, 0, 3, 1));
+        assert_eq!(diff, vec![(2, 0, &cell("称")), (4, 0, &cell("号"))]);
+    }
+
     #[test]
     fn buffer_merge() {
         let mut one = Buffer::filled(
@@ -1060,6 +1167,26 @@ mod tests {
         assert_eq!(skipped, vec![true, true, false, false, false, false]);
     }
 
+    #[test]
+    fn blit_from() {
+        let src = Buffer::with_lines(vec!["abcde", "fghij", "klmno", "pqrst"]);
+        let mut dest = Buffer::empty(Rect::new(0, 0, 6, 5));
+        dest.blit_from(&src, Rect::new(1, 1, 3, 3), (2, 1));
+        assert_eq!(
+            dest,
+            Buffer::with_lines(vec!["      ", "  ghi ", "  lmn ", "  qrs ", "      ",])
+        );
+    }
+
+    #[test]
+    fn blit_from_clips_to_both_buffers() {
+        let src = Buffer::with_lines(vec!["abc", "def", "ghi"]);
+        let mut dest = Buffer::empty(Rect::new(0, 0, 4, 3));
+        // the source area overflows the source buffer and the destination buffer
+        dest.blit_from(&src, Rect::new(1, 1, 5, 5), (2, 1));
+        assert_eq!(dest, Buffer::with_lines(vec!["    ", "  ef", "  hi"]));
+    }
+
     #[test]
     fn with_lines_accepts_into_lines() {
         use crate::style::Stylize;
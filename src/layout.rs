@@ -103,6 +103,15 @@ pub enum Alignment {
     Right,
 }
 
+/// Vertical alignment of content within an area that is taller than the content
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
 /// A constraint that can be applied to a layout
 ///
 /// Constraints are used to define the size of a layout. They can be used to define a fixed size, a
@@ -238,6 +247,35 @@ pub enum SegmentSize {
     None,
 }
 
+/// Stable subset of [`SegmentSize`] controlling how extra space is distributed among the columns
+/// of a [`Table`]
+///
+/// This is used by [`Table::flex`]. The other ways of distributing the space are experimental and
+/// only available through the unstable `segment_size` methods.
+///
+/// Defaults to [`Flex::None`], as a [`Table`] does not distribute the extra space by default.
+///
+/// [`Table`]: crate::widgets::Table
+/// [`Table::flex`]: crate::widgets::Table::flex
+#[derive(Copy, Debug, Default, Display, EnumString, Clone, Eq, PartialEq, Hash)]
+pub enum Flex {
+    /// the last chunk is expanded to fill the remaining space
+    LastTakesRemainder,
+
+    /// extra space is not distributed
+    #[default]
+    None,
+}
+
+impl From<Flex> for SegmentSize {
+    fn from(flex: Flex) -> Self {
+        match flex {
+            Flex::LastTakesRemainder => SegmentSize::LastTakesRemainder,
+            Flex::None => SegmentSize::None,
+        }
+    }
+}
+
 /// A container used by the solver inside split
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct Element {
@@ -1155,6 +1193,33 @@ mod tests {
         assert_eq!("".parse::<Alignment>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn vertical_alignment_to_string() {
+        assert_eq!(VerticalAlignment::Top.to_string(), "Top");
+        assert_eq!(VerticalAlignment::Center.to_string(), "Center");
+        assert_eq!(VerticalAlignment::Bottom.to_string(), "Bottom");
+    }
+
+    #[test]
+    fn vertical_alignment_from_str() {
+        assert_eq!(
+            "Top".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Top)
+        );
+        assert_eq!(
+            "Center".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Center)
+        );
+        assert_eq!(
+            "Bottom".parse::<VerticalAlignment>(),
+            Ok(VerticalAlignment::Bottom)
+        );
+        assert_eq!(
+            "".parse::<VerticalAlignment>(),
+            Err(ParseError::VariantNotFound)
+        );
+    }
+
     #[test]
     fn segment_size_to_string() {
         assert_eq!(
@@ -1182,6 +1247,43 @@ mod tests {
         assert_eq!("".parse::<SegmentSize>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn flex_to_string() {
+        assert_eq!(Flex::LastTakesRemainder.to_string(), "LastTakesRemainder");
+        assert_eq!(Flex::None.to_string(), "None");
+    }
+
+    #[test]
+    fn flex_from_string() {
+        assert_eq!(
+            "LastTakesRemainder".parse::<Flex>(),
+            Ok(Flex::LastTakesRemainder)
+        );
+        assert_eq!("None".parse::<Flex>(), Ok(Flex::None));
+        assert_eq!(
+            "EvenDistribution".parse::<Flex>(),
+            Err(ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn flex_into_segment_size() {
+        assert_eq!(
+            SegmentSize::from(Flex::LastTakesRemainder),
+            LastTakesRemainder
+        );
+        assert_eq!(SegmentSize::from(Flex::None), None);
+    }
+
+    #[test]
+    fn flex_default_matches_table_default() {
+        use crate::widgets::{Row, Table};
+
+        assert_eq!(Flex::default(), Flex::None);
+        let table = Table::new(Vec::<Row>::new(), [Length(5)]);
+        assert_eq!(table.clone().flex(Flex::default()), table);
+    }
+
     fn get_x_width_with_segment_size(
         segment_size: SegmentSize,
         constraints: Vec<Constraint>,
@@ -26,7 +26,9 @@ pub use crate::backend::TermwizBackend;
 pub use crate::{
     backend::{self, Backend},
     buffer::{self, Buffer},
-    layout::{self, Alignment, Constraint, Corner, Direction, Layout, Margin, Rect},
+    layout::{
+        self, Alignment, Constraint, Corner, Direction, Layout, Margin, Rect, VerticalAlignment,
+    },
     style::{self, Color, Modifier, Style, Styled, Stylize},
     symbols::{self, Marker},
     terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport},
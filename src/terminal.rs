@@ -36,7 +36,7 @@ use crate::{
     backend::{Backend, ClearType},
     buffer::Buffer,
     layout::Rect,
-    widgets::{StatefulWidget, Widget},
+    widgets::{StatefulWidget, Table, TableState, Widget},
 };
 
 /// Represents the viewport of the terminal. The viewport is the area of the terminal that is
@@ -45,7 +45,8 @@ use crate::{
 /// When the viewport is fullscreen, the whole terminal is used to draw the application.
 ///
 /// When the viewport is inline, it is drawn inline with the rest of the terminal. The height of
-/// the viewport is fixed, but the width is the same as the terminal width.
+/// the viewport is fixed, but the width is the same as the terminal width. An auto inline viewport
+/// is also drawn inline, but its height follows the height of its content.
 ///
 /// When the viewport is fixed, it is drawn in a fixed area of the terminal. The area is specified
 /// by a [`Rect`].
@@ -61,6 +62,27 @@ pub enum Viewport {
     /// The viewport's height is fixed and specified in number of lines. The width is the same as
     /// the terminal's width. The viewport is drawn below the cursor position.
     Inline(u16),
+    /// The viewport is inline with the rest of the terminal, and its height fits its content.
+    ///
+    /// The content is rendered with the `max` height, and reports the height it needs with
+    /// [`Frame::set_content_height`]. The viewport is then resized to that height, bounded by
+    /// `min` and `max`, and keeps the top of the rendered content. The viewport keeps its height
+    /// when the content does not report any height, and starts with the `min` height.
+    ///
+    /// [`Terminal::draw`] does not query the rendered widgets for their height, and widgets never
+    /// report it themselves, e.g. a [`Table`] does not call [`Frame::set_content_height`]. The
+    /// application must report it in the draw closure, e.g. with
+    /// `frame.set_content_height(table.min_size(rows).1)` (see [`Table::min_size`]). Otherwise the
+    /// viewport keeps its `min` height.
+    ///
+    /// [`Table`]: crate::widgets::Table
+    /// [`Table::min_size`]: crate::widgets::Table::min_size
+    InlineAuto {
+        /// The minimum height of the viewport
+        min: u16,
+        /// The maximum height of the viewport
+        max: u16,
+    },
     /// The viewport is drawn in a fixed area of the terminal. The area is specified by a [`Rect`].
     Fixed(Rect),
 }
@@ -70,6 +92,7 @@ impl fmt::Display for Viewport {
         match self {
             Viewport::Fullscreen => write!(f, "Fullscreen"),
             Viewport::Inline(height) => write!(f, "Inline({})", height),
+            Viewport::InlineAuto { min, max } => write!(f, "InlineAuto({}..={})", min, max),
             Viewport::Fixed(area) => write!(f, "Fixed({})", area),
         }
     }
@@ -151,6 +174,12 @@ where
     /// Last known position of the cursor. Used to find the new area when the viewport is inlined
     /// and the terminal resized.
     last_known_cursor_pos: (u16, u16),
+    /// Whether [`Terminal::draw`] resizes the internal buffers to match the backend size
+    autoresize: bool,
+    /// Whether the last flush wrote any change to the backend
+    last_flush_changed: bool,
+    /// Whether the next frame starts with the content of the previous frame
+    retain_buffer: bool,
 }
 
 impl<B> Drop for Terminal<B>
@@ -205,12 +234,16 @@ where
     /// ```
     pub fn with_options(mut backend: B, options: TerminalOptions) -> io::Result<Terminal<B>> {
         let size = match options.viewport {
-            Viewport::Fullscreen | Viewport::Inline(_) => backend.size()?,
+            Viewport::Fullscreen | Viewport::Inline(_) | Viewport::InlineAuto { .. } => {
+                backend.size()?
+            }
             Viewport::Fixed(area) => area,
         };
         let (viewport_area, cursor_pos) = match options.viewport {
             Viewport::Fullscreen => (size, (0, 0)),
-            Viewport::Inline(height) => compute_inline_size(&mut backend, height, size, 0)?,
+            Viewport::Inline(height) | Viewport::InlineAuto { min: height, .. } => {
+                compute_inline_size(&mut backend, height, size, 0)?
+            }
             Viewport::Fixed(area) => (area, (area.left(), area.top())),
         };
         Ok(Terminal {
@@ -222,6 +255,9 @@ where
             viewport_area,
             last_known_size: size,
             last_known_cursor_pos: cursor_pos,
+            autoresize: true,
+            last_flush_changed: true,
+            retain_buffer: false,
         })
     }
 
@@ -231,6 +267,7 @@ where
             cursor_position: None,
             viewport_area: self.viewport_area,
             buffer: self.current_buffer_mut(),
+            content_height: None,
         }
     }
 
@@ -249,6 +286,31 @@ where
         &mut self.backend
     }
 
+    /// Gets the viewport the terminal was created with
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Gets the current area of the viewport
+    ///
+    /// For inline viewports, this reflects the position of the viewport after it was moved by
+    /// [`Terminal::insert_before`] or by a resize.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// # let backend = TestBackend::new(10, 5);
+    /// let viewport = Viewport::Inline(1);
+    /// let mut terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// terminal.insert_before(2, |_| {})?;
+    /// assert_eq!(terminal.viewport_area(), Rect::new(0, 2, 10, 1));
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn viewport_area(&self) -> Rect {
+        self.viewport_area
+    }
+
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
@@ -258,6 +320,39 @@ where
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = (*col, *row);
         }
+        self.last_flush_changed = !updates.is_empty();
+        self.backend.draw(updates.into_iter())
+    }
+
+    /// Obtains a difference between the previous and the current buffer inside `area` and passes
+    /// it to the current backend for drawing.
+    ///
+    /// This is a cheaper [`Terminal::flush`] when only a part of the screen changed, e.g. when a
+    /// single widget is redrawn in an otherwise static screen: only the cells inside `area` are
+    /// compared, and the cells outside of `area` are not written to the backend, even when they
+    /// changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// # let backend = TestBackend::new(10, 2);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal
+    ///     .current_buffer_mut()
+    ///     .set_string(0, 0, "Hello", Style::new());
+    /// terminal.flush_area(Rect::new(0, 0, 2, 1))?;
+    /// assert_eq!(terminal.backend().last_drawn_cells(), [(0, 0), (1, 0)]);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn flush_area(&mut self, area: Rect) -> io::Result<()> {
+        let previous_buffer = &self.buffers[1 - self.current];
+        let current_buffer = &self.buffers[self.current];
+        let updates = previous_buffer.diff_area(current_buffer, area);
+        if let Some((col, row, _)) = updates.last() {
+            self.last_known_cursor_pos = (*col, *row);
+        }
+        self.last_flush_changed = !updates.is_empty();
         self.backend.draw(updates.into_iter())
     }
 
@@ -268,7 +363,11 @@ where
     pub fn resize(&mut self, size: Rect) -> io::Result<()> {
         let next_area = match self.viewport {
             Viewport::Fullscreen => size,
-            Viewport::Inline(height) => {
+            Viewport::Inline(_) | Viewport::InlineAuto { .. } => {
+                let height = match self.viewport {
+                    Viewport::Inline(height) => height,
+                    _ => self.viewport_area.height,
+                };
                 let offset_in_previous_viewport = self
                     .last_known_cursor_pos
                     .1
@@ -279,6 +378,10 @@ where
         };
         self.set_viewport_area(next_area);
         self.clear()?;
+        if self.retain_buffer {
+            // the retained content does not match the new area
+            self.buffers[self.current].reset();
+        }
 
         self.last_known_size = size;
         Ok(())
@@ -293,7 +396,7 @@ where
     /// Queries the backend for size and resizes if it doesn't match the previous size.
     pub fn autoresize(&mut self) -> io::Result<()> {
         // fixed viewports do not get autoresized
-        if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
+        if !matches!(self.viewport, Viewport::Fixed(_)) {
             let size = self.size()?;
             if size != self.last_known_size {
                 self.resize(size)?;
@@ -302,10 +405,78 @@ where
         Ok(())
     }
 
+    /// Enables or disables the automatic resizing of the terminal in [`Terminal::draw`].
+    ///
+    /// Autoresize is enabled by default. When disabled, [`Terminal::draw`] keeps using the last
+    /// known size until [`Terminal::resize`] or [`Terminal::autoresize`] is called explicitly. This
+    /// is useful for fixed-layout applications, or applications that handle resize events
+    /// themselves and want to avoid reallocating the buffers on spurious resize events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.set_autoresize(false);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_autoresize(&mut self, enabled: bool) {
+        self.autoresize = enabled;
+    }
+
+    /// Keeps the content of the previous frame in the buffer of the next frame.
+    ///
+    /// Disabled by default, in which case each [`Terminal::draw`] starts with an empty buffer. When
+    /// enabled, each frame starts with the content of the previous frame, so widgets that only
+    /// draw what changed, e.g. an [incremental] [`Table`], render correctly with
+    /// [`Terminal::draw`]. The buffer is still emptied when the viewport is resized.
+    ///
+    /// [incremental]: crate::widgets::Table::incremental
+    /// [`Table`]: crate::widgets::Table
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.set_retain_buffer(true);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_retain_buffer(&mut self, enabled: bool) {
+        self.retain_buffer = enabled;
+    }
+
+    /// Whether the last flush wrote any change to the backend
+    ///
+    /// This is computed from the same buffer diff that [`Terminal::flush`] writes to the backend,
+    /// and is `true` until the first frame is flushed. It reports what the last flush did, not
+    /// whether the next draw is needed: the terminal cannot know whether the content of the next
+    /// frame changes. Applications that redraw periodically can use it to detect that their
+    /// content settled (e.g. to stop an animation timer and sleep until the next input) instead
+    /// of drawing identical frames over and over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// terminal.draw(|f| f.render_widget(Paragraph::new("Hello"), f.size()))?;
+    /// terminal.draw(|f| f.render_widget(Paragraph::new("Hello"), f.size()))?;
+    /// assert!(!terminal.last_flush_changed());
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn last_flush_changed(&self) -> bool {
+        self.last_flush_changed
+    }
+
     /// Synchronizes terminal size, calls the rendering closure, flushes the current internal state
     /// and prepares for the next draw call.
     ///
-    /// This is the main entry point for drawing to the terminal.
+    /// This is the main entry point for drawing to the terminal. The terminal size is not
+    /// synchronized when autoresize is disabled with [`Terminal::set_autoresize`].
     ///
     /// # Examples
     ///
@@ -327,14 +498,20 @@ where
     {
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
-        self.autoresize()?;
+        if self.autoresize {
+            self.autoresize()?;
+        }
 
-        let mut frame = self.get_frame();
-        f(&mut frame);
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
-        let cursor_position = frame.cursor_position;
+        let cursor_position = if let Viewport::InlineAuto { min, max } = self.viewport {
+            self.draw_inline_auto(min, max, f)?
+        } else {
+            let mut frame = self.get_frame();
+            f(&mut frame);
+            frame.cursor_position
+        };
 
         // Draw to stdout
         self.flush()?;
@@ -358,6 +535,56 @@ where
         })
     }
 
+    /// Renders `f` with the maximum height of an auto inline viewport, resizes the viewport to the
+    /// height reported by the frame and keeps the top of the rendered content.
+    ///
+    /// Returns the cursor position set by the frame, moved with the viewport.
+    fn draw_inline_auto<F>(&mut self, min: u16, max: u16, f: F) -> io::Result<Option<(u16, u16)>>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let max = max.min(self.last_known_size.height);
+        let scratch_area = Rect {
+            height: max,
+            ..self.viewport_area
+        };
+        let mut scratch = Buffer::empty(scratch_area);
+        let mut frame = Frame {
+            cursor_position: None,
+            viewport_area: scratch_area,
+            buffer: &mut scratch,
+            content_height: None,
+        };
+        f(&mut frame);
+        let (cursor_position, content_height) = (frame.cursor_position, frame.content_height);
+
+        let height = content_height
+            .unwrap_or(self.viewport_area.height)
+            .max(min)
+            .min(max);
+        if height != self.viewport_area.height {
+            // the viewport grows from its top, scrolling the terminal when it reaches the bottom
+            self.backend
+                .set_cursor(self.viewport_area.left(), self.viewport_area.top())?;
+            let (area, _) =
+                compute_inline_size(&mut self.backend, height, self.last_known_size, 0)?;
+            self.set_viewport_area(area);
+            self.clear()?;
+        }
+        let area = self.viewport_area;
+        self.current_buffer_mut().blit_from(
+            &scratch,
+            Rect {
+                height,
+                ..scratch_area
+            },
+            (area.x, area.y),
+        );
+        // a cursor set above the rendered content is kept at the top of the viewport
+        Ok(cursor_position
+            .map(|(x, y)| (x, y.saturating_add(area.y).saturating_sub(scratch_area.y))))
+    }
+
     /// Hides the cursor.
     pub fn hide_cursor(&mut self) -> io::Result<()> {
         self.backend.hide_cursor()?;
@@ -391,7 +618,7 @@ where
     pub fn clear(&mut self) -> io::Result<()> {
         match self.viewport {
             Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
-            Viewport::Inline(_) => {
+            Viewport::Inline(_) | Viewport::InlineAuto { .. } => {
                 self.backend
                     .set_cursor(self.viewport_area.left(), self.viewport_area.top())?;
                 self.backend.clear_region(ClearType::AfterCursor)?;
@@ -409,8 +636,20 @@ where
     }
 
     /// Clears the inactive buffer and swaps it with the current buffer
+    ///
+    /// The inactive buffer gets the content of the current buffer instead of being cleared when
+    /// [`Terminal::set_retain_buffer`] is enabled.
     pub fn swap_buffers(&mut self) {
-        self.buffers[1 - self.current].reset();
+        if self.retain_buffer {
+            let [first, second] = &mut self.buffers;
+            if self.current == 0 {
+                second.clone_from(first);
+            } else {
+                first.clone_from(second);
+            }
+        } else {
+            self.buffers[1 - self.current].reset();
+        }
         self.current = 1 - self.current;
     }
 
@@ -446,6 +685,12 @@ where
     /// +-------------------+
     /// ```
     ///
+    /// The `height` is not limited by the size of the terminal. When the buffer is taller than the
+    /// space above the viewport, its lines scroll through that space in chunks, so that the first
+    /// lines end up in the scrollback of the terminal and the last lines of the buffer remain
+    /// visible right above the viewport. When the viewport takes the whole height of the terminal,
+    /// there is no space to display the buffer and nothing is inserted.
+    ///
     /// # Examples
     ///
     /// ## Insert a single line before the current viewport
@@ -467,7 +712,10 @@ where
     where
         F: FnOnce(&mut Buffer),
     {
-        if !matches!(self.viewport, Viewport::Inline(_)) {
+        if !matches!(
+            self.viewport,
+            Viewport::Inline(_) | Viewport::InlineAuto { .. }
+        ) {
             return Ok(());
         }
 
@@ -497,6 +745,10 @@ where
 
         // Split buffer into screen-sized chunks and draw
         let max_chunk_size = (self.viewport_area.top() * area.width).into();
+        if max_chunk_size == 0 {
+            // there is no space above the viewport to display the buffer
+            return Ok(());
+        }
         for buffer_content_chunk in buffer.content.chunks(max_chunk_size) {
             let chunk_size = buffer_content_chunk.len() as u16 / area.width;
 
@@ -518,6 +770,85 @@ where
 
         Ok(())
     }
+
+    /// Insert a widget before the current inline viewport. This has no effect when the viewport
+    /// is fullscreen.
+    ///
+    /// This is a shorthand for [`Terminal::insert_before`] with a closure rendering the widget in
+    /// the whole area of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ## Insert a paragraph before the current viewport
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.insert_before_widget(1, Paragraph::new("This line will be added before"));
+    /// ```
+    pub fn insert_before_widget<W>(&mut self, height: u16, widget: W) -> io::Result<()>
+    where
+        W: Widget,
+    {
+        self.insert_before(height, |buf| widget.render(buf.area, buf))
+    }
+
+    /// Insert some content of variable height before the current inline viewport. This has no
+    /// effect when the viewport is fullscreen.
+    ///
+    /// This is similar to [`Terminal::insert_before`], but the height of the content does not need
+    /// to be known beforehand. The `draw_fn` closure renders into a scratch `Buffer` of
+    /// `max_height` lines and returns the number of lines it actually used. The viewport is then
+    /// scrolled down by that number of lines only, and the unused lines are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ## Insert wrapped log lines before the current viewport
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// let lines = ["a short line", "a much longer line that will be wrapped"];
+    /// terminal.insert_before_with(10, |buf| {
+    ///     let mut y = 0;
+    ///     for line in lines {
+    ///         for chunk in line.as_bytes().chunks(buf.area.width as usize) {
+    ///             let chunk = std::str::from_utf8(chunk).unwrap();
+    ///             buf.set_string(buf.area.x, buf.area.y + y, chunk, Style::default());
+    ///             y += 1;
+    ///         }
+    ///     }
+    ///     y
+    /// });
+    /// ```
+    pub fn insert_before_with<F>(&mut self, max_height: u16, draw_fn: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Buffer) -> u16,
+    {
+        if !matches!(
+            self.viewport,
+            Viewport::Inline(_) | Viewport::InlineAuto { .. }
+        ) {
+            return Ok(());
+        }
+
+        let mut area = Rect {
+            x: self.viewport_area.left(),
+            y: 0,
+            width: self.viewport_area.width,
+            height: max_height,
+        };
+        let mut buffer = Buffer::empty(area);
+        area.height = draw_fn(&mut buffer).min(max_height);
+        if area.height == 0 {
+            return Ok(());
+        }
+        buffer.resize(area);
+
+        self.insert_before(area.height, |buf| *buf = buffer)
+    }
 }
 
 fn compute_inline_size<B: Backend>(
@@ -577,6 +908,9 @@ pub struct Frame<'a> {
 
     /// The buffer that is used to draw the current frame
     buffer: &'a mut Buffer,
+
+    /// The height of the content reported with [`Frame::set_content_height`]
+    content_height: Option<u16>,
 }
 
 impl Frame<'_> {
@@ -645,6 +979,36 @@ impl Frame<'_> {
         widget.render(area, self.buffer, state);
     }
 
+    /// Render a [`Table`] to the current buffer and return the range of rows that were drawn.
+    ///
+    /// This is a shortcut for [`Frame::render_stateful_widget`] followed by
+    /// [`TableState::visible_rows`]. The returned `(start, end)` range can be used by event
+    /// handlers to limit hit-testing to the visible rows, `start` being inclusive and `end`
+    /// exclusive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(5, 2);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let rows = (0..10).map(|i| Row::new(vec![i.to_string()]));
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let mut state = TableState::default().with_selected(Some(5));
+    /// let (start, end) = frame.render_table(table, Rect::new(0, 0, 5, 2), &mut state);
+    /// assert_eq!((start, end), (4, 6));
+    /// ```
+    pub fn render_table(
+        &mut self,
+        table: Table,
+        area: Rect,
+        state: &mut TableState,
+    ) -> (usize, usize) {
+        self.render_stateful_widget(table, area, state);
+        state.visible_rows()
+    }
+
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
@@ -655,6 +1019,31 @@ impl Frame<'_> {
         self.cursor_position = Some((x, y));
     }
 
+    /// Reports the height needed by the content of the frame
+    ///
+    /// With a [`Viewport::InlineAuto`], the viewport is resized to this height after the frame is
+    /// rendered, within the bounds of the viewport. The height is ignored by the other viewports.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 20);
+    /// let viewport = Viewport::InlineAuto { min: 1, max: 10 };
+    /// let mut terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// terminal.draw(|frame| {
+    ///     let rows = [Row::new(vec!["Cell1"]), Row::new(vec!["Cell2"])];
+    ///     let table = Table::new(rows, [Constraint::Length(5)]);
+    ///     frame.set_content_height(table.min_size(2).1);
+    ///     frame.render_widget(table, frame.size());
+    /// })?;
+    /// assert_eq!(terminal.viewport_area().height, 2);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_content_height(&mut self, height: u16) {
+        self.content_height = Some(height);
+    }
+
     /// Gets the buffer that this `Frame` draws into as a mutable reference.
     pub fn buffer_mut(&mut self) -> &mut Buffer {
         self.buffer
@@ -680,6 +1069,10 @@ mod tests {
     fn viewport_to_string() {
         assert_eq!(Viewport::Fullscreen.to_string(), "Fullscreen");
         assert_eq!(Viewport::Inline(5).to_string(), "Inline(5)");
+        assert_eq!(
+            Viewport::InlineAuto { min: 1, max: 10 }.to_string(),
+            "InlineAuto(1..=10)"
+        );
         assert_eq!(
             Viewport::Fixed(Rect::new(0, 0, 5, 5)).to_string(),
             "Fixed(5x5+0+0)"
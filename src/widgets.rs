@@ -50,7 +50,11 @@ pub use self::{
     paragraph::{Paragraph, Wrap},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
     sparkline::{RenderDirection, Sparkline},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
+    table::{
+        Anchor, CaptionPosition, Cell, CellLayout, HighlightSpacing, HighlightTheme,
+        HorizontalDirection, InvalidSelection, Row, ScrollMode, Table, TableError, TableLayout,
+        TableState, Truncation,
+    },
     tabs::Tabs,
 };
 use crate::{buffer::Buffer, layout::Rect};
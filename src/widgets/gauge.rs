@@ -215,7 +215,7 @@ impl<'a> Widget for Gauge<'a> {
     }
 }
 
-fn get_unicode_block<'a>(frac: f64) -> &'a str {
+pub(crate) fn get_unicode_block<'a>(frac: f64) -> &'a str {
     match (frac * 8.0).round() as u16 {
         1 => symbols::block::ONE_EIGHTH,
         2 => symbols::block::ONE_QUARTER,
@@ -1,7 +1,17 @@
 #![warn(missing_docs)]
 
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
 use strum::{Display, EnumString};
 
+use crate::{layout::Rect, style::Style};
+
 mod cell;
 mod row;
 #[allow(clippy::module_inception)]
@@ -51,6 +61,214 @@ impl HighlightSpacing {
     }
 }
 
+/// Determines which edge of the table the rows are attached to
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum Anchor {
+    /// The rows fill the table from the top edge downward
+    #[default]
+    Top,
+
+    /// The rows fill the table from the bottom edge upward
+    ///
+    /// When the rows do not fill the table, they are displayed at the bottom of the table. This is
+    /// useful for log or chat views, where the newest row is at the bottom.
+    Bottom,
+}
+
+/// Determines the order in which the columns of a [`Table`] are laid out, see
+/// [`Table::direction`]
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum HorizontalDirection {
+    /// The first column is on the left edge, and the selection column on the left of it
+    #[default]
+    LeftToRight,
+
+    /// The first column is on the right edge, and the selection column on the right of it
+    ///
+    /// This is useful for right-to-left languages.
+    RightToLeft,
+}
+
+/// Determines where the caption of a [`Table`] is displayed, see [`Table::caption_position`]
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum CaptionPosition {
+    /// The caption is displayed on the last line of the table
+    #[default]
+    Bottom,
+
+    /// The caption is displayed right below the last row
+    BelowRows,
+}
+
+/// Determines what happens when the selected row of a [`TableState`] is past the last row
+///
+/// This commonly happens when the rows are refreshed with fewer rows than before.
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum InvalidSelection {
+    /// Select the last row instead
+    #[default]
+    Clamp,
+
+    /// Clear the selection
+    Clear,
+
+    /// Keep the selection as is, without scrolling the rows
+    ///
+    /// No row is highlighted and the rows are displayed from the current offset, as if the
+    /// selected row was the first displayed row.
+    KeepOffset,
+}
+
+/// Determines how the rows are scrolled to show the selected row of a [`TableState`], see
+/// [`TableState::set_scroll_mode`]
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum ScrollMode {
+    /// Scroll the rows only when the selected row would leave the table
+    ///
+    /// The [`TableState::scroll_padding`] rows are kept visible around the selected row.
+    #[default]
+    KeepVisible,
+
+    /// Keep the selected row vertically centered, scrolling the rows around it
+    ///
+    /// Near the first and last rows, the rows are not scrolled past the ends of the data and the
+    /// selected row moves away from the center.
+    CenterSelection,
+}
+
+/// Determines which part of the content of a [`Cell`] is hidden when it is wider than its column
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum Truncation {
+    /// Hide the end of the content
+    #[default]
+    End,
+
+    /// Hide the start of the content
+    ///
+    /// This keeps the end of the content visible, which is useful for paths where the file name
+    /// is more relevant than the parent directories.
+    Start,
+
+    /// Hide the middle of the content
+    ///
+    /// This keeps both the start and the end of the content visible.
+    Middle,
+}
+
+/// A function stored in a [`Table`] or a [`Cell`], such as [`Table::row_decorator`]
+///
+/// Functions cannot be compared or hashed, so two functions are equal only when they are shared
+/// (i.e. the table or the cell was cloned).
+struct SharedFn<F: ?Sized>(Arc<F>);
+
+impl<F: ?Sized> Clone for SharedFn<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for SharedFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedFn")
+    }
+}
+
+impl<F: ?Sized> PartialEq for SharedFn<F> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<F: ?Sized> Eq for SharedFn<F> {}
+
+impl<F: ?Sized> Hash for SharedFn<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+/// The geometry of a [`Table`] computed when rendering, returned by [`Table::render_with_layout`]
+///
+/// This exposes where the content of the table was drawn, e.g. to let a screen reader describe
+/// the cells, or to map mouse positions to cells.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TableLayout {
+    /// The visible cells of the rows, in the order they were rendered
+    pub cells: Vec<CellLayout>,
+}
+
+/// The area and the content of a cell rendered by a [`Table`], see [`TableLayout`]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct CellLayout {
+    /// The index of the row of the cell in the rows of the table
+    pub row: usize,
+    /// The index of the cell in its row
+    pub col: usize,
+    /// The area of the screen the cell was rendered in
+    pub rect: Rect,
+    /// The content of the cell without styling, with its lines separated by `\n`
+    pub text: String,
+}
+
+/// The look of the selected row of a [`Table`], see [`Table::highlight_theme`]
+///
+/// This bundles the highlight symbol, style and spacing, so that the same selection look can be
+/// defined once and applied to many tables.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use ratatui::{prelude::*, widgets::*};
+/// const THEME: HighlightTheme = HighlightTheme {
+///     symbol: Cow::Borrowed(">> "),
+///     style: Style::new().add_modifier(Modifier::REVERSED),
+///     spacing: HighlightSpacing::Always,
+/// };
+/// let table = Table::default().highlight_theme(THEME);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct HighlightTheme<'a> {
+    /// The symbol displayed in front of the selected row
+    pub symbol: Cow<'a, str>,
+    /// The style of the selected row
+    pub style: Style,
+    /// When the column of the highlight symbol is reserved
+    pub spacing: HighlightSpacing,
+}
+
+/// Error returned by [`Table::try_new`] when the widths of the columns are invalid
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TableError {
+    /// The number of widths does not match the number of cells of the widest row
+    WidthsMismatch {
+        /// The number of widths
+        widths: usize,
+        /// The number of cells of the widest row
+        columns: usize,
+    },
+
+    /// A [`Constraint::Percentage`] width is greater than 100
+    ///
+    /// [`Constraint::Percentage`]: crate::layout::Constraint::Percentage
+    InvalidPercentage(u16),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::WidthsMismatch { widths, columns } => {
+                write!(f, "{widths} widths were given for {columns} columns")
+            }
+            TableError::InvalidPercentage(p) => {
+                write!(f, "Percentage {p} should be between 0 and 100 inclusively")
+            }
+        }
+    }
+}
+
+impl Error for TableError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +302,122 @@ mod tests {
             Err(strum::ParseError::VariantNotFound)
         );
     }
+
+    #[test]
+    fn anchor_to_string() {
+        assert_eq!(Anchor::Top.to_string(), "Top");
+        assert_eq!(Anchor::Bottom.to_string(), "Bottom");
+    }
+
+    #[test]
+    fn anchor_from_str() {
+        assert_eq!("Top".parse::<Anchor>(), Ok(Anchor::Top));
+        assert_eq!("Bottom".parse::<Anchor>(), Ok(Anchor::Bottom));
+        assert_eq!(
+            "".parse::<Anchor>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn horizontal_direction_to_string() {
+        assert_eq!(HorizontalDirection::LeftToRight.to_string(), "LeftToRight");
+        assert_eq!(HorizontalDirection::RightToLeft.to_string(), "RightToLeft");
+    }
+
+    #[test]
+    fn horizontal_direction_from_str() {
+        assert_eq!(
+            "LeftToRight".parse::<HorizontalDirection>(),
+            Ok(HorizontalDirection::LeftToRight)
+        );
+        assert_eq!(
+            "RightToLeft".parse::<HorizontalDirection>(),
+            Ok(HorizontalDirection::RightToLeft)
+        );
+        assert_eq!(
+            "".parse::<HorizontalDirection>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn table_error_to_string() {
+        let error = TableError::WidthsMismatch {
+            widths: 2,
+            columns: 3,
+        };
+        assert_eq!(error.to_string(), "2 widths were given for 3 columns");
+        assert_eq!(
+            TableError::InvalidPercentage(101).to_string(),
+            "Percentage 101 should be between 0 and 100 inclusively"
+        );
+    }
+
+    #[test]
+    fn invalid_selection_to_string() {
+        assert_eq!(InvalidSelection::Clamp.to_string(), "Clamp");
+        assert_eq!(InvalidSelection::Clear.to_string(), "Clear");
+        assert_eq!(InvalidSelection::KeepOffset.to_string(), "KeepOffset");
+    }
+
+    #[test]
+    fn invalid_selection_from_str() {
+        assert_eq!(
+            "Clamp".parse::<InvalidSelection>(),
+            Ok(InvalidSelection::Clamp)
+        );
+        assert_eq!(
+            "Clear".parse::<InvalidSelection>(),
+            Ok(InvalidSelection::Clear)
+        );
+        assert_eq!(
+            "KeepOffset".parse::<InvalidSelection>(),
+            Ok(InvalidSelection::KeepOffset)
+        );
+        assert_eq!(
+            "".parse::<InvalidSelection>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn scroll_mode_to_string() {
+        assert_eq!(ScrollMode::KeepVisible.to_string(), "KeepVisible");
+        assert_eq!(ScrollMode::CenterSelection.to_string(), "CenterSelection");
+    }
+
+    #[test]
+    fn scroll_mode_from_str() {
+        assert_eq!(
+            "KeepVisible".parse::<ScrollMode>(),
+            Ok(ScrollMode::KeepVisible)
+        );
+        assert_eq!(
+            "CenterSelection".parse::<ScrollMode>(),
+            Ok(ScrollMode::CenterSelection)
+        );
+        assert_eq!(
+            "".parse::<ScrollMode>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn truncation_to_string() {
+        assert_eq!(Truncation::End.to_string(), "End");
+        assert_eq!(Truncation::Start.to_string(), "Start");
+        assert_eq!(Truncation::Middle.to_string(), "Middle");
+    }
+
+    #[test]
+    fn truncation_from_str() {
+        assert_eq!("End".parse::<Truncation>(), Ok(Truncation::End));
+        assert_eq!("Start".parse::<Truncation>(), Ok(Truncation::Start));
+        assert_eq!("Middle".parse::<Truncation>(), Ok(Truncation::Middle));
+        assert_eq!(
+            "".parse::<Truncation>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
 }
@@ -1,4 +1,20 @@
-use crate::prelude::*;
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use super::{SharedFn, Truncation};
+use crate::{
+    prelude::*,
+    text::StyledGrapheme,
+    widgets::{
+        gauge::get_unicode_block,
+        reflow::{LineComposer, WordWrapper, WrappedLine},
+    },
+};
 
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
 ///
@@ -26,6 +42,9 @@ use crate::prelude::*;
 /// Cell::from(Text::from(Cow::Borrowed("hello")));
 /// ```
 ///
+/// For content that is expensive to format, [`Cell::from_fn`] creates a cell whose content is only
+/// computed when the cell is rendered.
+///
 /// `Cell` implements [`Styled`] which means you can use style shorthands from the [`Stylize`] trait
 /// to set the style of the cell concisely.
 ///
@@ -38,8 +57,13 @@ use crate::prelude::*;
 /// [`Table`]: super::Table
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Cell<'a> {
-    content: Text<'a>,
+    content: CellContent<'a>,
     style: Style,
+    column_span: usize,
+    truncation: Truncation,
+    fill_char: Option<char>,
+    padding_left: u16,
+    padding_right: u16,
 }
 
 impl<'a> Cell<'a> {
@@ -64,8 +88,74 @@ impl<'a> Cell<'a> {
         T: Into<Text<'a>>,
     {
         Self {
-            content: content.into(),
+            content: CellContent::Text(content.into()),
             style: Style::default(),
+            column_span: 1,
+            truncation: Truncation::End,
+            fill_char: None,
+            padding_left: 0,
+            padding_right: 0,
+        }
+    }
+
+    /// Creates a new [`Cell`] whose content is computed by a function when the cell is rendered
+    ///
+    /// The function returns any value that can be converted into a [`Text`]. It is only called
+    /// when the cell is rendered, so the content of the cells of the rows that are not visible is
+    /// never computed. The text is not cached: the function is called again each time the content
+    /// is needed, e.g. to measure the cell when the height of its row is computed with
+    /// [`Row::auto_height`], or to export it with [`Table::to_delimited`]. It should therefore be
+    /// cheap and return the same text on each call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let value = 42;
+    /// let cell = Cell::from_fn(move || format!("{value:>10}"));
+    /// ```
+    ///
+    /// [`Row::auto_height`]: super::Row::auto_height
+    /// [`Table::to_delimited`]: super::Table::to_delimited
+    pub fn from_fn<F, T>(content: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'a,
+        T: Into<Text<'a>>,
+    {
+        let content: Arc<LazyContentFn<'a>> = Arc::new(move || content().into());
+        Self {
+            content: CellContent::Lazy(SharedFn(content)),
+            ..Self::new("")
+        }
+    }
+
+    /// Creates a new [`Cell`] displaying a horizontal bar filling `ratio` of its width
+    ///
+    /// The bar is drawn on the first line of the cell with block characters in the given `style`,
+    /// the last character being a partial block for the fraction of a character. The `ratio` is
+    /// clamped between `0.0` and `1.0`, and a `NaN` ratio is treated as `0.0`. This is useful to
+    /// embed a mini gauge in a dashboard table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec![
+    ///     Cell::new("CPU"),
+    ///     Cell::gauge(0.42, Style::new().green()),
+    /// ]);
+    /// ```
+    pub fn gauge(ratio: f64, style: Style) -> Self {
+        Self {
+            content: CellContent::Gauge(GaugeBar {
+                ratio: if ratio.is_nan() {
+                    0.0
+                } else {
+                    ratio.clamp(0.0, 1.0)
+                },
+                style,
+            }),
+            ..Self::new("")
         }
     }
 
@@ -92,7 +182,7 @@ impl<'a> Cell<'a> {
     where
         T: Into<Text<'a>>,
     {
-        self.content = content.into();
+        self.content = CellContent::Text(content.into());
         self
     }
 
@@ -124,40 +214,489 @@ impl<'a> Cell<'a> {
         self.style = style;
         self
     }
+
+    /// Set which part of the content is hidden when it is wider than the column
+    ///
+    /// By default, the end of the content is hidden ([`Truncation::End`]). Use
+    /// [`Truncation::Start`] to keep the end of the content visible (e.g. the file name of a
+    /// path), or [`Truncation::Middle`] to keep both its start and its end visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Cell::new("/home/user/my_file.rs").truncation(Truncation::Start);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Set the character used to pad the content to the width of the column
+    ///
+    /// By default, the width of the column that is not covered by the content is left blank. When
+    /// set, the uncovered width of each line of the content is filled with `fill_char` instead,
+    /// after the content for left aligned lines and before it for right aligned lines. This can be
+    /// used to create dotted leaders (e.g. `Name......` next to a right aligned `42`).
+    ///
+    /// The fill character is expected to be one cell wide.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec![
+    ///     Cell::new("Name").fill_char('.'),
+    ///     Cell::new(Line::from("42").alignment(Alignment::Right)).fill_char('.'),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn fill_char(mut self, fill_char: char) -> Self {
+        self.fill_char = Some(fill_char);
+        self
+    }
+
+    /// Set the padding inside the cell
+    ///
+    /// The content of the cell is drawn `left` cells away from the left edge of its column and
+    /// `right` cells away from its right edge, so that it does not touch the content of the
+    /// neighboring columns. The padding is blank but has the style of the cell. This is distinct
+    /// from [`Table::column_spacing`], which is the space between the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let cell = Cell::new("Cell 1").padding(1, 1);
+    /// ```
+    ///
+    /// [`Table::column_spacing`]: super::Table::column_spacing
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn padding(mut self, left: u16, right: u16) -> Self {
+        self.padding_left = left;
+        self.padding_right = right;
+        self
+    }
+
+    /// Set the number of columns this cell spans
+    ///
+    /// A spanning cell covers the width of the following `columns - 1` columns and the spacing
+    /// between them, and those columns are skipped when rendering the rest of the row. This is
+    /// useful to create grouped headers. Spans are currently only honored in the header of a
+    /// [`Table`]. A span of `0` is treated as `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let header = Row::new(vec![Cell::new("Name").span(2), Cell::new("Age")]);
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn span(mut self, columns: usize) -> Self {
+        self.column_span = columns;
+        self
+    }
 }
 
-impl Cell<'_> {
+impl<'a> Cell<'a> {
+    /// Returns the number of columns the cell spans, which is at least 1.
+    pub(crate) fn column_span(&self) -> usize {
+        self.column_span.max(1)
+    }
+
+    /// Returns the number of lines of the content of the cell.
+    pub(crate) fn height(&self) -> usize {
+        self.content.text().height()
+    }
+
+    /// Returns the cell with its content wrapped on word boundaries to fit in `width`, including
+    /// the padding of the cell.
+    pub(crate) fn wrapped(&self, width: u16) -> Cell<'a> {
+        let width = width
+            .saturating_sub(self.padding_left)
+            .saturating_sub(self.padding_right);
+        let text = self.content.text();
+        let styled_lines = text.lines.iter().map(|line| {
+            (
+                line.spans
+                    .iter()
+                    .flat_map(|span| span.styled_graphemes(line.style)),
+                line.alignment.unwrap_or(Alignment::Left),
+            )
+        });
+        let mut wrapper = WordWrapper::new(styled_lines, width, true);
+        let mut lines = vec![];
+        while let Some(WrappedLine {
+            line, alignment, ..
+        }) = wrapper.next_line()
+        {
+            // the graphemes of the same style are gathered in a span
+            let mut spans: Vec<Span> = vec![];
+            for grapheme in line {
+                match spans.last_mut() {
+                    Some(span) if span.style == grapheme.style => {
+                        span.content.to_mut().push_str(grapheme.symbol);
+                    }
+                    _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+                }
+            }
+            lines.push(Line::from(spans).alignment(alignment));
+        }
+        Cell {
+            content: CellContent::Text(Text::from(lines)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the content of the cell without styling, with its lines separated by `\n`.
+    pub(crate) fn plain_text(&self) -> String {
+        self.content
+            .text()
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[cfg(test)]
     pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+        self.render_with_matches(area, buf, None, false);
+    }
+
+    /// Renders the cell, highlighting the parts of its content matching the query of `matches`.
+    ///
+    /// The lines are measured and truncated with [`UnicodeWidthStr::width_cjk`] when
+    /// `east_asian_width` is set, see [`Table::east_asian_width`].
+    ///
+    /// [`Table::east_asian_width`]: super::Table::east_asian_width
+    pub(crate) fn render_with_matches(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        matches: Option<&MatchHighlight>,
+        east_asian_width: bool,
+    ) {
         buf.set_style(area, self.style);
-        for (i, line) in self.content.lines.iter().enumerate() {
+        let left = self.padding_left.min(area.width);
+        let area = Rect {
+            x: area.x + left,
+            width: (area.width - left).saturating_sub(self.padding_right),
+            ..area
+        };
+        if let CellContent::Gauge(ref gauge) = self.content {
+            gauge.render(area, buf);
+            return;
+        }
+        let content = self.content.text();
+        for (i, line) in content.lines.iter().enumerate() {
             if i as u16 >= area.height {
                 break;
             }
+            let line = match matches {
+                Some(matches) => Cow::Owned(matches.highlight(line)),
+                None => Cow::Borrowed(line),
+            };
 
+            let line_width = line
+                .spans
+                .iter()
+                .map(|span| str_width(&span.content, east_asian_width))
+                .sum::<usize>();
             let x_offset = match line.alignment {
-                Some(Alignment::Center) => (area.width / 2).saturating_sub(line.width() as u16 / 2),
-                Some(Alignment::Right) => area.width.saturating_sub(line.width() as u16),
+                Some(Alignment::Center) => (area.width / 2).saturating_sub(line_width as u16 / 2),
+                Some(Alignment::Right) => area.width.saturating_sub(line_width as u16),
                 _ => 0,
             };
 
+            if let Some(fill_char) = self.fill_char {
+                for x in area.left()..area.right() {
+                    buf.get_mut(x, area.y + i as u16).set_char(fill_char);
+                }
+            }
+
             let x = area.x + x_offset;
             if x >= area.right() {
                 continue;
             }
 
-            buf.set_line(x, area.y + i as u16, line, area.width);
+            // the buffer truncates the end of the lines with the default width
+            let truncated = self.truncation != Truncation::End || east_asian_width;
+            if truncated && line_width > area.width as usize {
+                let y = area.y + i as u16;
+                self.render_truncated_line(&line, (x, y), area.width, east_asian_width, buf);
+            } else {
+                buf.set_line(x, area.y + i as u16, &line, area.width);
+            }
+        }
+    }
+
+    /// Renders a line that is wider than `width`, hiding its end, its start or its middle depending
+    /// on the truncation of the cell.
+    fn render_truncated_line(
+        &self,
+        line: &Line,
+        (x, y): (u16, u16),
+        width: u16,
+        east_asian_width: bool,
+        buf: &mut Buffer,
+    ) {
+        let graphemes: Vec<StyledGrapheme> = line
+            .spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::default()))
+            .collect();
+        let (head_width, tail_width) = match self.truncation {
+            Truncation::End => (width, 0),
+            Truncation::Start => (0, width),
+            Truncation::Middle => (width - width / 2, width / 2),
+        };
+        let head = take_width(graphemes.iter(), head_width, east_asian_width);
+        let mut tail = take_width(graphemes.iter().rev(), tail_width, east_asian_width);
+        tail.reverse();
+
+        let mut pos = x;
+        for grapheme in head {
+            (pos, _) = buf.set_stringn(pos, y, grapheme.symbol, width as usize, grapheme.style);
+        }
+        let tail_width: u16 = tail
+            .iter()
+            .map(|g| str_width(g.symbol, east_asian_width) as u16)
+            .sum();
+        let mut pos = x + width - tail_width;
+        for grapheme in tail {
+            (pos, _) = buf.set_stringn(pos, y, grapheme.symbol, width as usize, grapheme.style);
         }
     }
 }
 
+/// A function computing the content of a cell, see [`Cell::from_fn`]
+type LazyContentFn<'a> = dyn Fn() -> Text<'a> + Send + Sync + 'a;
+
+/// The content of a [`Cell`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum CellContent<'a> {
+    /// A text known when the cell is created
+    Text(Text<'a>),
+    /// A function computing the text when the cell is rendered
+    Lazy(SharedFn<LazyContentFn<'a>>),
+    /// A bar filling a part of the width of the cell
+    Gauge(GaugeBar),
+}
+
+impl Default for CellContent<'_> {
+    fn default() -> Self {
+        Self::Text(Text::default())
+    }
+}
+
+impl<'a> CellContent<'a> {
+    /// Returns the text of the content, calling the function of a lazy content.
+    fn text(&self) -> Cow<'_, Text<'a>> {
+        match self {
+            Self::Text(text) => Cow::Borrowed(text),
+            Self::Lazy(SharedFn(content)) => Cow::Owned(content()),
+            // the bar has no text but takes a line
+            Self::Gauge(_) => Cow::Owned(Text::from("")),
+        }
+    }
+}
+
+/// The bar of a [`Cell::gauge`]
+#[derive(Debug, Clone, Copy)]
+struct GaugeBar {
+    /// The part of the width filled by the bar, between `0.0` and `1.0`
+    ratio: f64,
+    style: Style,
+}
+
+impl GaugeBar {
+    /// Renders the bar on the first line of `area`.
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let filled_width = self.ratio * f64::from(area.width);
+        let full = filled_width.floor() as u16;
+        for x in area.left()..area.left() + full {
+            buf.get_mut(x, area.y)
+                .set_symbol(symbols::block::FULL)
+                .set_style(self.style);
+        }
+        if full < area.width {
+            let symbol = get_unicode_block(filled_width % 1.0);
+            if symbol != " " {
+                buf.get_mut(area.left() + full, area.y)
+                    .set_symbol(symbol)
+                    .set_style(self.style);
+            }
+        }
+    }
+}
+
+// the ratio is compared and hashed by its bits, as it is never NaN once clamped
+impl PartialEq for GaugeBar {
+    fn eq(&self, other: &Self) -> bool {
+        self.ratio.to_bits() == other.ratio.to_bits() && self.style == other.style
+    }
+}
+
+impl Eq for GaugeBar {}
+
+impl Hash for GaugeBar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ratio.to_bits().hash(state);
+        self.style.hash(state);
+    }
+}
+
+/// Highlights the parts of the content of the cells matching a query, see
+/// [`Table::highlight_matches`]
+///
+/// [`Table::highlight_matches`]: super::Table::highlight_matches
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) struct MatchHighlight<'q> {
+    pub(crate) query: &'q str,
+    pub(crate) style: Style,
+    pub(crate) ignore_case: bool,
+}
+
+impl MatchHighlight<'_> {
+    /// Returns the line with its spans split around the matches of the query, the matching parts
+    /// being patched with the style of the highlight.
+    fn highlight<'l>(&self, line: &'l Line) -> Line<'l> {
+        let content: String = line
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        let matches = self.find_matches(&content);
+        let mut spans = Vec::with_capacity(line.spans.len() + 2 * matches.len());
+        let mut span_start = 0;
+        for span in &line.spans {
+            let span_end = span_start + span.content.len();
+            // the boundaries of the matches within the span, relative to the start of the span
+            let mut cuts = vec![(0, false)];
+            for &(start, end) in &matches {
+                if start < span_end && end > span_start {
+                    cuts.push((start.max(span_start) - span_start, true));
+                    cuts.push((end.min(span_end) - span_start, false));
+                }
+            }
+            cuts.push((span.content.len(), false));
+            for (&(start, is_match), &(end, _)) in cuts.iter().zip(cuts.iter().skip(1)) {
+                if start < end {
+                    let style = if is_match {
+                        span.style.patch(self.style)
+                    } else {
+                        span.style
+                    };
+                    spans.push(Span::styled(&span.content[start..end], style));
+                }
+            }
+            span_start = span_end;
+        }
+        Line {
+            spans,
+            style: line.style,
+            alignment: line.alignment,
+        }
+    }
+
+    /// Returns the byte ranges of the non overlapping matches of the query in `content`, from left
+    /// to right.
+    fn find_matches(&self, content: &str) -> Vec<(usize, usize)> {
+        let mut matches = vec![];
+        if self.query.is_empty() {
+            return matches;
+        }
+        let mut start = 0;
+        while start < content.len() {
+            match self.match_at(&content[start..]) {
+                Some(len) => {
+                    matches.push((start, start + len));
+                    start += len;
+                }
+                None => {
+                    start += content[start..].chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+        matches
+    }
+
+    /// Returns the length in bytes of the match of the query at the start of `content`.
+    fn match_at(&self, content: &str) -> Option<usize> {
+        let mut chars = content.char_indices();
+        for query_char in self.query.chars() {
+            let (_, c) = chars.next()?;
+            let matches = if self.ignore_case {
+                c.to_lowercase().eq(query_char.to_lowercase())
+            } else {
+                c == query_char
+            };
+            if !matches {
+                return None;
+            }
+        }
+        Some(chars.next().map_or(content.len(), |(index, _)| index))
+    }
+}
+
+/// Takes graphemes from the iterator for as long as their total width fits in `max_width`.
+fn take_width<'a, 'b>(
+    graphemes: impl Iterator<Item = &'b StyledGrapheme<'a>>,
+    max_width: u16,
+    east_asian_width: bool,
+) -> Vec<&'b StyledGrapheme<'a>> {
+    let mut width = 0;
+    graphemes
+        .take_while(|grapheme| {
+            width += str_width(grapheme.symbol, east_asian_width);
+            width <= max_width as usize
+        })
+        .collect()
+}
+
+/// Returns the width of `s`, measuring the East Asian ambiguous width characters as double width
+/// when `east_asian_width` is set.
+pub(crate) fn str_width(s: &str, east_asian_width: bool) -> usize {
+    if east_asian_width {
+        s.width_cjk()
+    } else {
+        s.width()
+    }
+}
+
 impl<'a, T> From<T> for Cell<'a>
 where
     T: Into<Text<'a>>,
 {
     fn from(content: T) -> Cell<'a> {
         Cell {
-            content: content.into(),
+            content: CellContent::Text(content.into()),
             style: Style::default(),
+            column_span: 1,
+            truncation: Truncation::End,
+            fill_char: None,
+            padding_left: 0,
+            padding_right: 0,
         }
     }
 }
@@ -176,19 +715,85 @@ impl<'a> Styled for Cell<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
-    use crate::style::{Color, Modifier, Style, Stylize};
+    use crate::{
+        assert_buffer_eq,
+        style::{Color, Modifier, Style, Stylize},
+    };
 
     #[test]
     fn new() {
         let cell = Cell::new("");
-        assert_eq!(cell.content, Text::from(""));
+        assert_eq!(cell.content, CellContent::Text(Text::from("")));
+    }
+
+    #[test]
+    fn from_fn() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let cell = Cell::from_fn(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            "lazy"
+        });
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        cell.render(buf.area, &mut buf);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["lazy  "]));
+    }
+
+    #[test]
+    fn highlight_matches() {
+        let assert_highlight = |query, ignore_case, line: Line, expected: Line| {
+            let matches = MatchHighlight {
+                query,
+                style: Style::new().bold(),
+                ignore_case,
+            };
+            assert_eq!(matches.highlight(&line), expected);
+        };
+        assert_highlight(
+            "ell",
+            false,
+            Line::from("Cell1 cell2"),
+            Line::from(vec![
+                "C".into(),
+                "ell".bold(),
+                "1 c".into(),
+                "ell".bold(),
+                "2".into(),
+            ]),
+        );
+        // the matches do not overlap
+        assert_highlight(
+            "aa",
+            false,
+            Line::from("aaa"),
+            Line::from(vec!["aa".bold(), "a".into()]),
+        );
+        // a match across spans keeps the style of each span
+        assert_highlight(
+            "ll",
+            true,
+            Line::from(vec!["CEL".red(), "L".into()]),
+            Line::from(vec!["CE".red(), "L".red().bold(), "L".bold()]),
+        );
+        assert_highlight("", false, Line::from("abc"), Line::from("abc"));
+        assert_highlight(
+            "É",
+            true,
+            Line::from("café").alignment(Alignment::Right),
+            Line::from(vec!["caf".into(), "é".bold()]).alignment(Alignment::Right),
+        );
     }
 
     #[test]
     fn content() {
         let cell = Cell::default().content("");
-        assert_eq!(cell.content, Text::from(""));
+        assert_eq!(cell.content, CellContent::Text(Text::from("")));
     }
 
     #[test]
@@ -198,6 +803,232 @@ mod tests {
         assert_eq!(cell.style, style);
     }
 
+    #[test]
+    fn fill_char() {
+        let cell = Cell::default().fill_char('.');
+        assert_eq!(cell.fill_char, Some('.'));
+    }
+
+    #[test]
+    fn render_fill_char() {
+        let render = |content: Line<'static>| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+            Cell::new(content).fill_char('.').render(buf.area, &mut buf);
+            buf
+        };
+        assert_buffer_eq!(
+            render(Line::from("Name")),
+            Buffer::with_lines(vec!["Name......", "          "])
+        );
+        assert_buffer_eq!(
+            render(Line::from("42").alignment(Alignment::Right)),
+            Buffer::with_lines(vec!["........42", "          "])
+        );
+        assert_buffer_eq!(
+            render(Line::from("mid").alignment(Alignment::Center)),
+            Buffer::with_lines(vec!["....mid...", "          "])
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let cell = Cell::default().padding(1, 2);
+        assert_eq!((cell.padding_left, cell.padding_right), (1, 2));
+    }
+
+    #[test]
+    fn render_padding() {
+        let render = |cell: Cell| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            cell.render(buf.area, &mut buf);
+            buf
+        };
+        assert_buffer_eq!(
+            render(Cell::new("Name").padding(1, 0)),
+            Buffer::with_lines(vec![" Name "])
+        );
+        assert_buffer_eq!(
+            render(Cell::new("Name").padding(1, 2)),
+            Buffer::with_lines(vec![" Nam  "])
+        );
+        assert_buffer_eq!(
+            render(Cell::new(Line::from("42").alignment(Alignment::Right)).padding(0, 1)),
+            Buffer::with_lines(vec!["   42 "])
+        );
+        assert_buffer_eq!(
+            render(Cell::new("Name").padding(1, 1).fill_char('.')),
+            Buffer::with_lines(vec![" Name "])
+        );
+        assert_buffer_eq!(
+            render(Cell::new("Name").padding(10, 0)),
+            Buffer::with_lines(vec!["      "])
+        );
+    }
+
+    #[test]
+    fn truncation() {
+        let cell = Cell::default().truncation(Truncation::Middle);
+        assert_eq!(cell.truncation, Truncation::Middle);
+    }
+
+    #[test]
+    fn render_truncation() {
+        let path = "/home/user/my_file.rs";
+        let render = |truncation| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            Cell::new(path)
+                .truncation(truncation)
+                .render(buf.area, &mut buf);
+            buf
+        };
+        assert_buffer_eq!(
+            render(Truncation::End),
+            Buffer::with_lines(vec!["/home/user"])
+        );
+        assert_buffer_eq!(
+            render(Truncation::Start),
+            Buffer::with_lines(vec!["my_file.rs"])
+        );
+        assert_buffer_eq!(
+            render(Truncation::Middle),
+            Buffer::with_lines(vec!["/homele.rs"])
+        );
+    }
+
+    #[test]
+    fn str_width_with_east_asian_width() {
+        let ambiguous = "→①○";
+        assert_eq!(str_width(ambiguous, false), 3);
+        assert_eq!(str_width(ambiguous, true), 6);
+        // characters with a non ambiguous width are measured the same way
+        assert_eq!(str_width("ab表", false), 4);
+        assert_eq!(str_width("ab表", true), 4);
+    }
+
+    #[test]
+    fn render_with_east_asian_width() {
+        let render = |cell: Cell, east_asian_width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            cell.render_with_matches(buf.area, &mut buf, None, east_asian_width);
+            buf
+        };
+        let cell = Cell::new("①②③④");
+        assert_buffer_eq!(
+            render(cell.clone(), false),
+            Buffer::with_lines(vec!["①②③④  "])
+        );
+        // the ambiguous width characters take two cells each on CJK terminals
+        assert_buffer_eq!(
+            render(cell.clone(), true),
+            Buffer::with_lines(vec!["①②③   "])
+        );
+        assert_buffer_eq!(
+            render(cell.truncation(Truncation::Start), true),
+            Buffer::with_lines(vec!["②③④   "])
+        );
+        assert_buffer_eq!(
+            render(Cell::new(Line::from("①").alignment(Alignment::Right)), true),
+            Buffer::with_lines(vec!["    ① "])
+        );
+    }
+
+    #[test]
+    fn render_truncation_keeps_span_styles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Cell::new(Line::from(vec!["abc".into(), "def".red()]))
+            .truncation(Truncation::Start)
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["cdef"]);
+        expected.set_style(Rect::new(1, 0, 3, 1), Style::new().red());
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_truncation_of_short_content() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Cell::new("short")
+            .truncation(Truncation::Start)
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["short     "]));
+    }
+
+    #[test]
+    fn wrapped() {
+        let cell = Cell::new(Line::from(vec!["Long ".red(), "description".into()])).padding(1, 0);
+        let wrapped = cell.wrapped(9);
+        assert_eq!(wrapped.plain_text(), "Long\ndescript\nion");
+        assert_eq!(wrapped.height(), 3);
+        assert_eq!(wrapped.padding_left, 1);
+        let CellContent::Text(text) = wrapped.content else {
+            panic!("the wrapped content is a text");
+        };
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::styled("Long", Style::new().red())]
+        );
+    }
+
+    #[test]
+    fn gauge() {
+        let style = Style::new().green();
+        let cell = Cell::gauge(0.5, style);
+        assert_eq!(
+            cell.content,
+            CellContent::Gauge(GaugeBar { ratio: 0.5, style })
+        );
+        assert_eq!(cell.height(), 1);
+        assert_eq!(Cell::gauge(1.5, style), Cell::gauge(1.0, style));
+        assert_eq!(Cell::gauge(-1.0, style), Cell::gauge(0.0, style));
+        assert_eq!(Cell::gauge(f64::NAN, style), Cell::gauge(0.0, style));
+    }
+
+    #[test]
+    fn render_gauge() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Cell::gauge(0.5, Style::new().green()).render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["█████     "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().green());
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_gauge_with_partial_block() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Cell::gauge(0.25, Style::new().green())
+            .padding(1, 1)
+            .render(buf.area, &mut buf);
+        // 2.0 characters of the 8 characters left by the padding are filled
+        let mut expected = Buffer::with_lines(vec![" ██       "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().green());
+        assert_buffer_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Cell::gauge(0.625, Style::new()).render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["██▌ "]));
+    }
+
+    #[test]
+    fn render_gauge_clamps_ratio() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Cell::gauge(2.0, Style::new()).render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["████"]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Cell::gauge(-1.0, Style::new()).render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["    "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Cell::gauge(f64::NAN, Style::new()).render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["    "]));
+    }
+
+    #[test]
+    fn span() {
+        assert_eq!(Cell::new("").column_span(), 1);
+        assert_eq!(Cell::default().column_span(), 1);
+        assert_eq!(Cell::new("").span(2).column_span(), 2);
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use super::*;
 use crate::prelude::*;
 
@@ -59,8 +61,15 @@ use crate::prelude::*;
 pub struct Row<'a> {
     pub(crate) cells: Vec<Cell<'a>>,
     pub(crate) height: u16,
+    pub(crate) auto_height: bool,
     pub(crate) bottom_margin: u16,
     pub(crate) style: Style,
+    pub(crate) vertical_alignment: VerticalAlignment,
+    pub(crate) widths_override: Option<Vec<Constraint>>,
+    pub(crate) detail: Option<Text<'a>>,
+    pub(crate) full_width: bool,
+    pub(crate) unselectable: bool,
+    pub(crate) wrap: bool,
 }
 
 impl<'a> Row<'a> {
@@ -123,6 +132,7 @@ impl<'a> Row<'a> {
     /// Set the fixed height of the [`Row`]
     ///
     /// Any [`Cell`] whose content has more lines than this height will see its content truncated.
+    /// A row with a height of `0` is not displayed, but it can still be selected.
     ///
     /// By default, the height is `1`.
     ///
@@ -141,6 +151,27 @@ impl<'a> Row<'a> {
         self
     }
 
+    /// Measure the height of the [`Row`] from the content of its cells
+    ///
+    /// Instead of using a fixed height, the row will be as tall as the cell with the most lines, so
+    /// that multi-line cells are not truncated. A row whose cells are all empty has a height of
+    /// `1`. This overrides any height set with [`Row::height`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let cells = vec!["Cell 1\nline 2\nline 3", "Cell 2", "Cell 3"];
+    /// let row = Row::new(cells).auto_height();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn auto_height(mut self) -> Self {
+        self.auto_height = true;
+        self
+    }
+
     /// Set the bottom margin. By default, the bottom margin is `0`.
     ///
     /// The bottom margin is the number of blank lines to be displayed after the row.
@@ -160,6 +191,149 @@ impl<'a> Row<'a> {
         self
     }
 
+    /// Set the vertical alignment of the content of the cells
+    ///
+    /// When the row is taller than the content of a cell, the content is placed at the top
+    /// (default), in the center, or at the bottom of the row. This is useful for headers that are
+    /// taller than their labels, e.g. when some cells contain a group label above the column name.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let cells = vec!["Group\nName", "Age"];
+    /// let row = Row::new(cells)
+    ///     .height(2)
+    ///     .vertical_alignment(VerticalAlignment::Bottom);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Set the widths of the columns of this row only
+    ///
+    /// The columns of the row are laid out with these constraints instead of the widths of the
+    /// [`Table`], which allows "jagged" layouts, e.g. a section header row with a single full width
+    /// column between detail rows using the normal columns. The column spacing of the table is
+    /// still applied between the columns of the row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let section = Row::new(vec!["Section 1"]).widths_override([Constraint::Percentage(100)]);
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn widths_override<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Constraint>,
+    {
+        self.widths_override = Some(widths.into_iter().map(|c| *c.as_ref()).collect());
+        self
+    }
+
+    /// Set whether the first cell of the row spans the full width of the table
+    ///
+    /// By default the cells of a row are laid out in the columns of the [`Table`], so a row with
+    /// fewer cells than the table has columns leaves the remaining columns empty. A full width row
+    /// renders its first cell across all the columns instead, e.g. for a header or a summary row
+    /// with a single long cell. The other cells of the row are ignored.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Updated on Dec 28"]).full_width(true);
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn full_width(mut self, full_width: bool) -> Self {
+        self.full_width = full_width;
+        self
+    }
+
+    /// Set whether the content of the cells is wrapped on word boundaries to fit their columns
+    ///
+    /// This is only honored for the [`Table::header`], so that long labels of narrow columns are
+    /// wrapped across the lines of the header instead of being truncated. The header must be tall
+    /// enough for the wrapped lines, either with [`Row::height`] or with [`Row::auto_height`],
+    /// which then measures the wrapped lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let header = Row::new(vec!["Name", "Description"]).wrap(true).height(2);
+    /// ```
+    ///
+    /// [`Table::header`]: super::Table::header
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set whether the row can be selected
+    ///
+    /// Rows are selectable by default. [`Table::select_next`] and [`Table::select_previous`] skip
+    /// over the rows that are not selectable, e.g. the rows separating the sections of a table.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let separator = Row::new(vec!["Section 2"]).selectable(false);
+    /// ```
+    ///
+    /// [`Table::select_next`]: super::Table::select_next
+    /// [`Table::select_previous`]: super::Table::select_previous
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.unselectable = !selectable;
+        self
+    }
+
+    /// Set the detail of the row, displayed when the row is expanded
+    ///
+    /// The `detail` parameter accepts any value that can be converted into a [`Text`]. It is
+    /// rendered below the cells of the row, in the extra lines given to the row by
+    /// [`Table::expanded_row_height`], and is not displayed while the row is collapsed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Cell1", "Cell2"]).detail("first detail\nsecond detail");
+    /// ```
+    ///
+    /// [`Table::expanded_row_height`]: super::Table::expanded_row_height
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn detail<T>(mut self, detail: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.detail = Some(detail.into());
+        self
+    }
+
     /// Set the [`Style`] of the entire row
     ///
     /// This [`Style`] can be overridden by the [`Style`] of a any individual [`Cell`] or by their
@@ -190,11 +364,123 @@ impl<'a> Row<'a> {
     }
 }
 
+impl Row<'_> {
+    /// Returns the height of the row, without its margin
+    ///
+    /// This is the height set with [`Row::height`], or the number of lines of the tallest cell
+    /// when [`Row::auto_height`] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Cell1", "Cell2"]).height(2);
+    /// assert_eq!(row.content_height(), 2);
+    /// ```
+    pub fn content_height(&self) -> u16 {
+        if self.auto_height {
+            let lines = self.cells.iter().map(Cell::height).max().unwrap_or(0);
+            u16::try_from(lines).unwrap_or(u16::MAX).max(1)
+        } else {
+            self.height
+        }
+    }
+
+    /// Returns the vertical margin of the row
+    ///
+    /// This is the margin set with [`Row::bottom_margin`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Cell1", "Cell2"]).bottom_margin(1);
+    /// assert_eq!(row.vertical_margin(), 1);
+    /// ```
+    pub fn vertical_margin(&self) -> u16 {
+        self.bottom_margin
+    }
+
+    /// Returns the total height the row occupies in a [`Table`], including its margin
+    ///
+    /// This can be used to compute the layout of other widgets that must be kept in sync with the
+    /// rows of a table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Cell1", "Cell2"]).height(2).bottom_margin(1);
+    /// assert_eq!(row.total_height(), 3);
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    pub fn total_height(&self) -> u16 {
+        self.height_with_margin()
+    }
+
+    /// Returns the text of the cell at `column` without styling, or `None` when the row has no
+    /// such cell
+    ///
+    /// The lines of the cell are separated by `\n`. This can be used to locate a row from its
+    /// content, e.g. with [`Table::select_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec![Cell::from("Cell1").red(), Cell::from("Cell2")]);
+    /// assert_eq!(row.cell_text(0).as_deref(), Some("Cell1"));
+    /// assert_eq!(row.cell_text(2), None);
+    /// ```
+    ///
+    /// [`Table::select_by`]: super::Table::select_by
+    pub fn cell_text(&self, column: usize) -> Option<String> {
+        self.cells.get(column).map(Cell::plain_text)
+    }
+}
+
 // private methods for rendering
 impl Row<'_> {
+    /// Returns the `(x, width)` of the columns of the row, which is a single column spanning all
+    /// the `columns_widths` of the table for a full width row.
+    pub(crate) fn columns_widths<'c>(
+        &self,
+        columns_widths: &'c [(u16, u16)],
+    ) -> Cow<'c, [(u16, u16)]> {
+        // the columns are not in increasing order when laid out right to left
+        let left = columns_widths.iter().map(|&(x, _)| x).min();
+        let right = columns_widths.iter().map(|&(x, width)| x + width).max();
+        match (self.full_width, left, right) {
+            (true, Some(left), Some(right)) => Cow::Owned(vec![(left, right - left)]),
+            _ => Cow::Borrowed(columns_widths),
+        }
+    }
+
+    /// Returns the area of `cell` within the `area` of the row, offset vertically according to the
+    /// vertical alignment of the row.
+    pub(crate) fn cell_area(&self, cell: &Cell, area: Rect) -> Rect {
+        // the content of the cell is only measured when needed, as it may be computed lazily
+        let free_height = || {
+            let cell_height = u16::try_from(cell.height()).unwrap_or(u16::MAX);
+            self.content_height().saturating_sub(cell_height)
+        };
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => free_height() / 2,
+            VerticalAlignment::Bottom => free_height(),
+        }
+        .min(area.height);
+        Rect {
+            y: area.y + offset,
+            height: area.height - offset,
+            ..area
+        }
+    }
+
     /// Returns the total height of the row.
     pub(crate) fn height_with_margin(&self) -> u16 {
-        self.height.saturating_add(self.bottom_margin)
+        self.content_height().saturating_add(self.vertical_margin())
     }
 }
 
@@ -237,12 +523,105 @@ mod tests {
         assert_eq!(row.height, 2);
     }
 
+    #[test]
+    fn auto_height() {
+        let row = Row::new(vec!["a\nb\nc", "d"]).height(1).auto_height();
+        assert_eq!(row.content_height(), 3);
+
+        let row = Row::new(vec![""]).auto_height();
+        assert_eq!(row.content_height(), 1);
+
+        let row = Row::default().auto_height();
+        assert_eq!(row.content_height(), 1);
+    }
+
     #[test]
     fn bottom_margin() {
         let row = Row::default().bottom_margin(1);
         assert_eq!(row.bottom_margin, 1);
     }
 
+    #[test]
+    fn vertical_alignment() {
+        let row = Row::default().vertical_alignment(VerticalAlignment::Center);
+        assert_eq!(row.vertical_alignment, VerticalAlignment::Center);
+    }
+
+    #[test]
+    fn wrap() {
+        assert!(!Row::default().wrap);
+        let row = Row::default().wrap(true);
+        assert!(row.wrap);
+    }
+
+    #[test]
+    fn selectable() {
+        assert!(!Row::default().unselectable);
+        let row = Row::default().selectable(false);
+        assert!(row.unselectable);
+        let row = row.selectable(true);
+        assert!(!row.unselectable);
+    }
+
+    #[test]
+    fn full_width() {
+        let row = Row::default().full_width(true);
+        assert!(row.full_width);
+    }
+
+    #[test]
+    fn cell_area() {
+        let area = Rect::new(0, 0, 5, 3);
+        let cell = Cell::from("a");
+        let row = Row::new(vec![cell.clone()]).height(3);
+        assert_eq!(row.cell_area(&cell, area), Rect::new(0, 0, 5, 3));
+        let row = row.vertical_alignment(VerticalAlignment::Center);
+        assert_eq!(row.cell_area(&cell, area), Rect::new(0, 1, 5, 2));
+        let row = row.vertical_alignment(VerticalAlignment::Bottom);
+        assert_eq!(row.cell_area(&cell, area), Rect::new(0, 2, 5, 1));
+    }
+
+    #[test]
+    fn cell_text() {
+        let row = Row::new(vec![
+            Cell::from(Line::from(vec!["Cell".red(), "1".into()])),
+            Cell::from("Line1\nLine2"),
+        ]);
+        assert_eq!(row.cell_text(0).as_deref(), Some("Cell1"));
+        assert_eq!(row.cell_text(1).as_deref(), Some("Line1\nLine2"));
+        assert_eq!(row.cell_text(2), None);
+    }
+
+    #[test]
+    fn total_height() {
+        let row = Row::default().height(2).bottom_margin(1);
+        assert_eq!(row.content_height(), 2);
+        assert_eq!(row.vertical_margin(), 1);
+        assert_eq!(
+            row.total_height(),
+            row.content_height() + row.vertical_margin()
+        );
+
+        let row = Row::new(vec![Cell::from("a\nb\nc")]).auto_height();
+        assert_eq!(row.content_height(), 3);
+        assert_eq!(row.total_height(), 3);
+    }
+
+    #[test]
+    fn widths_override() {
+        let row = Row::default().widths_override([Constraint::Length(3), Constraint::Min(1)]);
+        assert_eq!(
+            row.widths_override,
+            Some(vec![Constraint::Length(3), Constraint::Min(1)])
+        );
+    }
+
+    #[test]
+    fn detail() {
+        let row = Row::default().detail("a\nb");
+        assert_eq!(row.detail, Some(Text::from("a\nb")));
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
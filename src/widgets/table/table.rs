@@ -1,13 +1,21 @@
-use std::iter;
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BTreeSet,
+    sync::Arc,
+};
 
 use itertools::Itertools;
-use unicode_width::UnicodeWidthStr;
 
-use super::*;
+use super::{
+    cell::{self, MatchHighlight},
+    table_state::{ColumnsWidthsCache, ColumnsWidthsKey},
+    *,
+};
 use crate::{
-    layout::SegmentSize,
+    layout::{Flex, SegmentSize},
     prelude::*,
-    widgets::{Block, StatefulWidget, Widget},
+    symbols::line,
+    widgets::{Block, BorderType, StatefulWidget, Widget},
 };
 
 /// A widget to display data in formatted columns.
@@ -44,13 +52,58 @@ use crate::{
 ///
 /// - [`Table::rows`] sets the rows of the [`Table`].
 /// - [`Table::header`] sets the header row of the [`Table`].
+/// - [`Table::header_separator`] draws a horizontal rule below the header.
+/// - [`Table::pinned_top_rows`] sets the rows displayed above the scrollable rows.
+/// - [`Table::sticky_header`] sets whether the header scrolls away with the rows.
+/// - [`Table::caption`] sets a caption line displayed below the [`Table`].
+/// - [`Table::caption_position`] sets whether the caption is displayed at the bottom of the table
+///   or right below the rows.
 /// - [`Table::widths`] sets the width constraints of each column.
 /// - [`Table::column_spacing`] sets the spacing between each column.
+/// - [`Table::min_width_for_spacing`] sets the width below which the column spacing is removed.
+/// - [`Table::distribute_remainder`] spreads the width left by the default equal widths.
+/// - [`Table::edge_padding`] sets the space before the first column and after the last column.
+/// - [`Table::grid`] draws a grid around every cell.
+/// - [`Table::skeleton_rows`] sets the placeholder rows rendered while the table has no rows.
+/// - [`Table::empty_fill`] fills the part of the table left empty by the rows.
+/// - [`Table::overflow_markers`] shows markers when rows are hidden above or below the rows area.
+/// - [`Table::overflow_marker_style`] sets the style of these markers.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
+/// - [`Table::cell_style`] sets the default style of the cells.
+/// - [`Table::column_styles`] sets the style of each column.
+/// - [`Table::alternating_column_styles`] tints the even and odd columns.
+/// - [`Table::column_range_style`] sets the style of the columns highlighted by the state.
+/// - [`Table::visible_columns`] hides some of the columns.
+/// - [`Table::direction`] sets whether the columns are laid out left to right or right to left.
+/// - [`Table::row_decorator`] computes an additional style for each row.
+/// - [`Table::filter`] hides the rows not matching a predicate.
+/// - [`Table::collapse_when_tiny`] renders a summary line instead of the rows in a tiny area.
+/// - [`Table::expanded_row_height`] computes the height of the expanded rows.
+/// - [`Table::expand_zone_width`] sets the width of the clickable zone expanding a row.
+/// - [`Table::hover_style`] sets the style of the row under the mouse cursor.
 /// - [`Table::highlight_style`] sets the style of the selected row.
+/// - [`Table::highlight_fg`], [`Table::highlight_bg`] and [`Table::highlight_modifier`] change a
+///   single property of the style of the selected row.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
+/// - [`Table::highlight_symbol_style`] sets the style of the selection column of the selected row.
+/// - [`Table::highlight_symbol_use_highlight_style`] draws the highlight symbol without the style
+///   of the row.
+/// - [`Table::highlight_symbol_repeat`] draws the highlight symbol on every line of the selected
+///   row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::highlight_theme`] sets the highlight symbol, style and spacing at once.
+/// - [`Table::highlight_matches`] highlights the parts of the cells matching a search query.
+/// - [`Table::highlight_matches_ignore_case`] sets whether the search query ignores case.
+/// - [`Table::anchor`] sets which edge of the table the rows are attached to.
+/// - [`Table::on_invalid_selection`] sets what happens when the selected row is past the last row.
+/// - [`Table::selection_width_override`] forces the width reserved for the selection column.
+/// - [`Table::east_asian_width`] measures ambiguous width characters as double width.
+/// - [`Table::min_column_width`] sets the minimum width of a displayed column.
+/// - [`Table::flex`] sets how extra space is distributed amongst the columns.
+/// - [`Table::width_resolver`] replaces the computation of the widths of the columns.
+/// - [`Table::incremental`] only draws the rows that changed since the last render.
+/// - [`Table::max_visible_rows`] limits the number of rows displayed at once.
 ///
 /// # Example
 ///
@@ -145,6 +198,19 @@ use crate::{
 /// let table = Table::new(rows, widths).red().italic();
 /// ```
 ///
+/// # Comparing tables
+///
+/// `Table` implements [`PartialEq`], [`Eq`] and [`Hash`] over all the fields that affect how it
+/// is rendered: the rows and their cells, the styles, the spacing, the widths, the
+/// [`Table::flex`] and the highlight configuration. Two tables that compare equal render
+/// identically with the same state, so the equality or the hash of a table can be used to skip
+/// rendering a table that did not change.
+///
+/// The functions given to the table (e.g. [`Table::filter`] or [`Cell::from_fn`]) are compared
+/// by identity: a table compares equal to its clones, but not to a table built with another
+/// closure, even an identical one. A function whose result depends on mutable state captured by
+/// the closure (e.g. a `RefCell`) can make equal tables render differently.
+///
 /// # Stateful example
 ///
 /// `Table` is a [`StatefulWidget`], which means you can use it with [`TableState`] to allow the
@@ -178,29 +244,149 @@ pub struct Table<'a> {
     /// Optional header
     header: Option<Row<'a>>,
 
+    /// Symbol of the rule drawn below the header
+    header_separator: Option<char>,
+
+    /// Rows displayed between the header and the scrollable rows
+    pinned_rows: Vec<Row<'a>>,
+
+    /// Whether the header scrolls away with the rows instead of staying at the top
+    scroll_header: bool,
+
+    /// Optional caption displayed below the rows
+    caption: Option<Line<'a>>,
+
+    /// Where the caption is displayed
+    caption_position: CaptionPosition,
+
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
     /// Space between each column
     column_spacing: u16,
 
+    /// Width below which the space between each column is removed
+    min_width_for_spacing: u16,
+
+    /// Whether the width left by the division of the default equal widths is distributed
+    distribute_remainder: bool,
+
+    /// Blank space before the first column and after the last column
+    edge_padding: u16,
+
+    /// Type of the lines of the grid drawn around every cell
+    grid: Option<BorderType>,
+
+    /// Number and style of the placeholder rows rendered while the table has no rows
+    skeleton_rows: Option<(usize, Style)>,
+
+    /// Symbol and style filling the part of the rows area left empty by the rows
+    empty_fill: Option<(char, Style)>,
+
+    /// Whether markers are displayed when rows are hidden above or below the rows area
+    overflow_markers: bool,
+
+    /// Style of the markers of the hidden rows
+    overflow_marker_style: Style,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
     /// Base style for the widget
     style: Style,
 
+    /// Default style of the header, pinned rows and rows, applied before their own styles
+    cell_style: Style,
+
+    /// Style of each column, layered between the row and the cell styles
+    column_styles: Vec<Style>,
+
+    /// Styles of the even and odd columns, layered beneath the row styles
+    alternating_column_styles: Option<(Style, Style)>,
+
+    /// Style used to render the columns of the range highlighted by the state
+    column_range_style: Style,
+
+    /// Number of leading columns left out by [`Table::skip_columns`]
+    column_offset: usize,
+
+    /// Whether each column is displayed, the columns without a value are displayed
+    visible_columns: Vec<bool>,
+
+    /// Order in which the columns are laid out
+    direction: HorizontalDirection,
+
+    /// Style used to render the row under the mouse cursor
+    hover_style: Style,
+
     /// Style used to render the selected row
     highlight_style: Style,
 
     /// Symbol in front of the selected rom
-    highlight_symbol: Option<&'a str>,
+    highlight_symbol: Option<Cow<'a, str>>,
+
+    /// Style used to render the selection column of the selected row
+    highlight_symbol_style: Style,
+
+    /// Whether the highlight symbol is drawn on every line of the selected row
+    highlight_symbol_repeat: bool,
+
+    /// Whether the highlight symbol is drawn with the highlight style instead of the row style
+    highlight_symbol_use_highlight_style: bool,
+
+    /// Query whose matches are highlighted in the cells of the rows
+    match_query: Option<Cow<'a, str>>,
+
+    /// Style patched onto the matches of the query
+    match_style: Style,
+
+    /// Whether the query matches the content of the cells regardless of case
+    match_ignore_case: bool,
 
     /// Decides when to allocate spacing for the row selection
     highlight_spacing: HighlightSpacing,
 
+    /// Function computing an additional style for each rendered row
+    row_decorator: Option<SharedFn<RowDecoratorFn<'a>>>,
+
+    /// Function computing the height of the expanded rows
+    expanded_row_height: Option<SharedFn<ExpandedRowHeightFn<'a>>>,
+
+    /// Width of the zone at the start of the first column toggling the expansion of a row
+    expand_zone_width: u16,
+
+    /// Function deciding which rows are displayed
+    filter: Option<SharedFn<RowFilterFn<'a>>>,
+
+    /// Function computing the line rendered instead of the table when its area is tiny
+    collapsed_summary: Option<SharedFn<CollapsedSummaryFn<'a>>>,
+
+    /// Edge of the table the rows are attached to
+    anchor: Anchor,
+
+    /// What happens when the selected row is past the last row
+    invalid_selection: InvalidSelection,
+
+    /// Width of the selection column, overriding the measured width of the highlight symbol
+    selection_width_override: Option<u16>,
+
+    /// Whether East Asian ambiguous width characters are measured as double width
+    east_asian_width: bool,
+
+    /// Minimum width of a displayed column, narrower columns are widened or dropped
+    min_column_width: u16,
+
     /// Controls how to distribute extra space among the columns
     segment_size: SegmentSize,
+
+    /// Function laying out the columns instead of the built-in layout
+    width_resolver: Option<SharedFn<WidthResolverFn<'a>>>,
+
+    /// Whether only the rows marked as dirty in the state are drawn
+    incremental: bool,
+
+    /// Maximum number of rows displayed at once, regardless of the height of the area
+    max_visible_rows: Option<u16>,
 }
 
 impl<'a> Table<'a> {
@@ -241,6 +427,88 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Creates a new [`Table`] widget, checking that the widths match the rows
+    ///
+    /// This is the same as [`Table::new`], except that instead of panicking on a percentage width
+    /// greater than 100, it returns [`TableError::InvalidPercentage`]. It also returns
+    /// [`TableError::WidthsMismatch`] when there are rows and the number of `widths` does not match
+    /// the number of cells of the widest row, which would otherwise leave columns empty or cells
+    /// hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// let table = Table::try_new(rows.clone(), [Constraint::Length(5); 2]);
+    /// assert!(table.is_ok());
+    ///
+    /// let table = Table::try_new(rows, [Constraint::Length(5)]);
+    /// assert_eq!(
+    ///     table,
+    ///     Err(TableError::WidthsMismatch {
+    ///         widths: 1,
+    ///         columns: 2
+    ///     })
+    /// );
+    /// ```
+    pub fn try_new<R, C>(rows: R, widths: C) -> Result<Self, TableError>
+    where
+        R: IntoIterator<Item = Row<'a>>,
+        C: IntoIterator,
+        C::Item: AsRef<Constraint>,
+    {
+        let widths = widths.into_iter().map(|c| *c.as_ref()).collect_vec();
+        if let Some(&Constraint::Percentage(p)) = widths
+            .iter()
+            .find(|w| matches!(w, Constraint::Percentage(p) if *p > 100))
+        {
+            return Err(TableError::InvalidPercentage(p));
+        }
+        let rows = rows.into_iter().collect_vec();
+        if let Some(columns) = rows.iter().map(|row| row.cells.len()).max() {
+            if columns != widths.len() {
+                return Err(TableError::WidthsMismatch {
+                    widths: widths.len(),
+                    columns,
+                });
+            }
+        }
+        Ok(Self::new(rows, widths))
+    }
+
+    /// Creates a new [`Table`] widget from rows with a fixed number of cells
+    ///
+    /// Each row is an array of `N` values that can be converted into [`Cell`]s, and `widths` holds
+    /// exactly `N` constraints, so a row with a missing or an extra cell, or a mismatched number of
+    /// widths, is caught at compile time. The rows are converted into [`Row`]s, as with
+    /// [`Table::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [["Cell1", "Cell2"], ["Cell3", "Cell4"]];
+    /// let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::from_rows(rows, widths);
+    /// ```
+    ///
+    /// The number of widths must match the number of cells of the rows:
+    ///
+    /// ```rust,compile_fail
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [["Cell1", "Cell2"], ["Cell3", "Cell4"]];
+    /// let widths = [Constraint::Length(5)];
+    /// let table = Table::from_rows(rows, widths);
+    /// ```
+    pub fn from_rows<const N: usize, R, T>(rows: R, widths: [Constraint; N]) -> Self
+    where
+        R: IntoIterator<Item = [T; N]>,
+        T: Into<Cell<'a>>,
+    {
+        Self::new(rows.into_iter().map(Row::new), widths)
+    }
+
     /// Set the rows
     ///
     /// The `rows` parameter accepts any value that can be converted into an iterator of [`Row`]s.
@@ -272,6 +540,74 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Appends a row to the end of the rows
+    ///
+    /// Unlike [`Table::rows`], this modifies the table in place, which avoids rebuilding the whole
+    /// [`Table`] when the rows of a long-lived table change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut table = Table::default();
+    /// table.push_row(Row::new(vec!["Cell1", "Cell2"]));
+    /// ```
+    pub fn push_row(&mut self, row: Row<'a>) {
+        self.rows.push(row);
+    }
+
+    /// Inserts a row at position `index`, shifting all rows after it down
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(5)];
+    /// let mut table = Table::new([Row::new(vec!["Cell2"])], widths);
+    /// table.insert_row(0, Row::new(vec!["Cell1"]));
+    /// ```
+    pub fn insert_row(&mut self, index: usize, row: Row<'a>) {
+        self.rows.insert(index, row);
+    }
+
+    /// Removes and returns the row at position `index`, shifting all rows after it up
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(5)];
+    /// let mut table = Table::new([Row::new(vec!["Cell1"])], widths);
+    /// let row = table.remove_row(0);
+    /// ```
+    pub fn remove_row(&mut self, index: usize) -> Row<'a> {
+        self.rows.remove(index)
+    }
+
+    /// Removes all the rows
+    ///
+    /// The header and the other properties of the table are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(5)];
+    /// let mut table = Table::new([Row::new(vec!["Cell1"])], widths);
+    /// table.clear_rows();
+    /// ```
+    pub fn clear_rows(&mut self) {
+        self.rows.clear();
+    }
+
     /// Sets the header row
     ///
     /// The `header` parameter is a [`Row`] which will be displayed at the top of the [`Table`]
@@ -294,6 +630,130 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Draws a horizontal rule below the header
+    ///
+    /// Unlike a [`Row::bottom_margin`] on the header, which leaves blank lines, the line right
+    /// below the header is filled with `separator` across the width of the table, and styled with
+    /// the style of the header. The rule takes one more line in the table, above the margin of the
+    /// header.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .header(Row::new(vec!["Head1", "Head2"]))
+    ///     .header_separator('─');
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_separator(mut self, separator: char) -> Self {
+        self.header_separator = Some(separator);
+        self
+    }
+
+    /// Sets the rows pinned at the top of the table
+    ///
+    /// The pinned rows are displayed between the header and the other rows, and never scroll.
+    /// This is useful for summary rows (e.g. totals) that must stay visible. The pinned rows
+    /// cannot be selected: the selected index and the offset of the [`TableState`] only apply to
+    /// the rows set with [`Table::rows`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let rows = [Row::new(vec!["a", "1"]), Row::new(vec!["b", "2"])];
+    /// let table = Table::new(rows, widths).pinned_top_rows([Row::new(vec!["Total", "3"])]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn pinned_top_rows<T>(mut self, rows: T) -> Self
+    where
+        T: IntoIterator<Item = Row<'a>>,
+    {
+        self.pinned_rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets whether the header stays at the top of the table when scrolling
+    ///
+    /// By default the header is sticky: it always occupies the top of the table and only the rows
+    /// scroll. When set to `false`, the header behaves as if it was the row before the first one,
+    /// and scrolls away once the table is scrolled (i.e. the offset is greater than `0`), leaving
+    /// its space to the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default()
+    ///     .header(Row::new(vec!["Header Cell 1", "Header Cell 2"]))
+    ///     .sticky_header(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sticky_header(mut self, sticky: bool) -> Self {
+        self.scroll_header = !sticky;
+        self
+    }
+
+    /// Sets the caption line
+    ///
+    /// The caption is displayed on the last line of the table area, below the rows, or right below
+    /// the last row depending on the [`Table::caption_position`]. Unlike the header, it is not
+    /// aligned with the columns: it spans the full width of the table and is positioned using the
+    /// alignment of the [`Line`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default().caption(Line::from("3 results").alignment(Alignment::Right));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn caption<T>(mut self, caption: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets where the caption is displayed
+    ///
+    /// By default, the caption is displayed on the last line of the table area, leaving blank
+    /// lines between the last row and the caption when the rows do not fill the table. With
+    /// [`CaptionPosition::BelowRows`], the caption is displayed right below the last row instead.
+    /// The caption stays at the bottom with [`Anchor::Bottom`], as the rows are attached to the
+    /// bottom edge of the table.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .caption("1 result")
+    ///     .caption_position(CaptionPosition::BelowRows);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn caption_position(mut self, position: CaptionPosition) -> Self {
+        self.caption_position = position;
+        self
+    }
+
     /// Set the widths of the columns.
     ///
     /// The `widths` parameter accepts anything which be converted to an Iterator of Constraints
@@ -326,6 +786,40 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Returns the width constraints of the columns set with [`Table::widths`]
+    ///
+    /// The constraints are empty when the widths were not set, in which case the columns are
+    /// rendered with equal widths (see [`Table::uses_equal_widths`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default().widths([Constraint::Length(5)]);
+    /// assert_eq!(table.get_widths(), [Constraint::Length(5)]);
+    /// ```
+    pub fn get_widths(&self) -> &[Constraint] {
+        &self.widths
+    }
+
+    /// Returns whether the columns are rendered with equal widths because no widths were set
+    ///
+    /// When [`Table::widths`] is empty, the width of the table is divided equally between the
+    /// columns. Applications can use this e.g. to warn that the widths are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default();
+    /// assert!(table.uses_equal_widths());
+    /// let table = table.widths([Constraint::Length(5)]);
+    /// assert!(!table.uses_equal_widths());
+    /// ```
+    pub fn uses_equal_widths(&self) -> bool {
+        self.widths.is_empty()
+    }
+
     /// Set the spacing between columns
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -344,10 +838,12 @@ impl<'a> Table<'a> {
         self
     }
 
-    /// Wraps the table with a custom [`Block`] widget.
+    /// Set the blank space before the first column and after the last column
     ///
-    /// The `block` parameter is of type [`Block`]. This holds the specified block to be
-    /// created around the [`Table`]
+    /// Unlike [`Table::column_spacing`], which only sits between the columns, this keeps the
+    /// content of the first and last columns away from the edges of the table. Unlike the padding
+    /// of a [`Block`], it applies even when the table has no block. The highlight symbol is not
+    /// moved, so the padding sits between the selection column and the first column.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -357,19 +853,19 @@ impl<'a> Table<'a> {
     /// # use ratatui::{prelude::*, widgets::*};
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let block = Block::default().title("Table").borders(Borders::ALL);
-    /// let table = Table::new(rows, widths).block(block);
+    /// let table = Table::new(rows, widths).edge_padding(1);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block);
+    pub fn edge_padding(mut self, padding: u16) -> Self {
+        self.edge_padding = padding;
         self
     }
 
-    /// Sets the base style of the widget
+    /// Set the placeholder rows rendered while the table has no rows
     ///
-    /// All text rendered by the widget will use this style, unless overridden by [`Block::style`],
-    /// [`Row::style`], [`Cell::style`], or the styles of cell's content.
+    /// When the table has no rows, e.g. while its data is being fetched, `count` rows of bars
+    /// styled with `style` are rendered instead, each bar filling the width of a column. This gives
+    /// an idea of the structure of the table before its content is available.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -377,30 +873,46 @@ impl<'a> Table<'a> {
     ///
     /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
-    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).style(Style::new().red().italic());
+    /// let table = Table::new(Vec::<Row>::new(), widths).skeleton_rows(3, Style::new().dark_gray());
     /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn skeleton_rows(mut self, count: usize, style: Style) -> Self {
+        self.skeleton_rows = Some((count, style));
+        self
+    }
+
+    /// Set the symbol and style filling the part of the table left empty by the rows
     ///
-    /// `Table` also implements the [`Styled`] trait, which means you can use style shorthands from
-    /// the [`Stylize`] trait to set the style of the widget more concisely.
+    /// When the rows do not fill the table, the lines below the last row (or above the first row
+    /// with [`Anchor::Bottom`]) are filled with `fill_char` and styled with `style`, e.g. to
+    /// display a dotted pattern. The lines taken by a [`Table::caption`] displayed right below the
+    /// rows are not filled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
-    /// # let widths = vec![Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).red().italic();
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).empty_fill('·', Style::new().dark_gray());
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn style(mut self, style: Style) -> Self {
-        self.style = style;
+    pub fn empty_fill(mut self, fill_char: char, style: Style) -> Self {
+        self.empty_fill = Some((fill_char, style));
         self
     }
 
-    /// Set the style of the selected row
+    /// Show markers when rows are hidden above or below the rows area
     ///
-    /// This style will be applied to the entire row, including the selection symbol if it is
-    /// displayed, and will override any style set on the row or on the individual cells.
+    /// When the table is scrolled down, the first line of the rows area displays `▲ more`, and when
+    /// rows exist beyond the bottom of the rows area, its last line displays `▼ more`. Both are
+    /// displayed when the rows area shows the middle of the rows. The markers take the place of
+    /// rows, and are not displayed when the rows area is less than 3 lines high.
+    ///
+    /// The markers are styled with [`Table::overflow_marker_style`].
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -410,15 +922,18 @@ impl<'a> Table<'a> {
     /// # use ratatui::{prelude::*, widgets::*};
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).highlight_style(Style::new().red().italic());
+    /// let table = Table::new(rows, widths).overflow_markers(true);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn highlight_style(mut self, highlight_style: Style) -> Self {
-        self.highlight_style = highlight_style;
+    pub fn overflow_markers(mut self, overflow_markers: bool) -> Self {
+        self.overflow_markers = overflow_markers;
         self
     }
 
-    /// Set the symbol to be displayed in front of the selected row
+    /// Set the style of the markers of the hidden rows, see [`Table::overflow_markers`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -428,29 +943,27 @@ impl<'a> Table<'a> {
     /// # use ratatui::{prelude::*, widgets::*};
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).highlight_symbol(">>");
+    /// let table = Table::new(rows, widths)
+    ///     .overflow_markers(true)
+    ///     .overflow_marker_style(Style::new().dark_gray());
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
-        self.highlight_symbol = Some(highlight_symbol);
+    pub fn overflow_marker_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.overflow_marker_style = style.into();
         self
     }
 
-    /// Set when to show the highlight spacing
+    /// Draw a grid around every cell
     ///
-    /// The highlight spacing is the spacing that is allocated for the selection symbol column (if
-    /// enabled) and is used to shift the table when a row is selected. This method allows you to
-    /// configure when this spacing is allocated.
+    /// The grid surrounds the header, the pinned rows and the rows with a box, and separates the
+    /// columns with vertical lines and the rows with horizontal lines, using the junction symbols
+    /// of `border_type` where the lines meet (e.g. `┼`, `├` or `┬`). The vertical lines replace the
+    /// [`Table::column_spacing`], and the horizontal lines take a line below each row, in addition
+    /// to its [`Row::bottom_margin`]. The caption is displayed below the grid, and the grid is
+    /// drawn inside the [`Block`] of the table.
     ///
-    /// - [`HighlightSpacing::Always`] will always allocate the spacing, regardless of whether a row
-    ///   is selected or not. This means that the table will never change size, regardless of if a
-    ///   row is selected or not.
-    /// - [`HighlightSpacing::WhenSelected`] will only allocate the spacing if a row is selected.
-    ///   This means that the table will shift when a row is selected. This is the default setting
-    ///   for backwards compatibility, but it is recommended to use `HighlightSpacing::Always` for a
-    ///   better user experience.
-    /// - [`HighlightSpacing::Never`] will never allocate the spacing, regardless of whether a row
-    ///   is selected or not. This means that the highlight symbol will never be drawn.
+    /// [`BorderType::QuadrantInside`] and [`BorderType::QuadrantOutside`] have no junction symbols,
+    /// so they draw the grid with plain lines.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -460,542 +973,6052 @@ impl<'a> Table<'a> {
     /// # use ratatui::{prelude::*, widgets::*};
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).highlight_spacing(HighlightSpacing::Always);
+    /// let table = Table::new(rows, widths).grid(BorderType::Plain);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
-        self.highlight_spacing = value;
+    pub fn grid(mut self, border_type: BorderType) -> Self {
+        self.grid = Some(border_type);
         self
     }
 
-    /// Set how extra space is distributed amongst columns.
+    /// Set the width below which the columns are rendered without spacing
     ///
-    /// This determines how the space is distributed when the constraints are satisfied. By default,
-    /// the extra space is not distributed at all.  But this can be changed to distribute all extra
-    /// space to the last column or to distribute it equally.
+    /// On narrow terminals, the [`Table::column_spacing`] takes cells away from the content. When
+    /// the width of the table (inside its [`Block`]) is less than `width`, the columns are rendered
+    /// next to each other as if the column spacing was `0`. Otherwise the configured spacing is
+    /// used.
+    ///
+    /// Defaults to `0`, which always applies the column spacing.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
     ///
-    /// Create a table that needs at least 30 columns to display.  Any extra space will be assigned
-    /// to the last column.
-    #[cfg_attr(feature = "unstable", doc = " ```")]
-    #[cfg_attr(not(feature = "unstable"), doc = " ```ignore")]
-    /// # use ratatui::layout::Constraint;
-    /// # use ratatui::layout::SegmentSize;
-    /// # use ratatui::widgets::Table;
-    /// let widths = [Constraint::Min(10), Constraint::Min(10), Constraint::Min(10)];
-    /// let table = Table::new([], widths)
-    ///     .segment_size(SegmentSize::LastTakesRemainder);
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// // drop the spacing when the table is narrower than 40 cells
+    /// let table = Table::new(rows, widths)
+    ///     .column_spacing(2)
+    ///     .min_width_for_spacing(40);
     /// ```
-    #[stability::unstable(
-        feature = "segment-size",
-        reason = "The name for this feature is not final and may change in the future",
-        issue = "https://github.com/ratatui-org/ratatui/issues/536"
-    )]
-    pub const fn segment_size(mut self, segment_size: SegmentSize) -> Self {
-        self.segment_size = segment_size;
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_width_for_spacing(mut self, width: u16) -> Self {
+        self.min_width_for_spacing = width;
         self
     }
-}
 
-impl Widget for Table<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let mut state = TableState::default();
-        StatefulWidget::render(self, area, buf, &mut state);
+    /// Set whether the width left over by the default equal widths is distributed to the columns
+    ///
+    /// When [`Table::widths`] is not set, the width of the table is divided equally between the
+    /// columns, and the cells left over by the division are left blank after the last column (or
+    /// given to the last column, depending on the [`Table::flex`]). When `distribute_remainder` is
+    /// `true`, these cells are given one per column to the first columns, so that the widths of the
+    /// columns differ by at most 1 and fill the table.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2", "Cell3"])];
+    /// let table = Table::default().rows(rows).distribute_remainder(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn distribute_remainder(mut self, distribute_remainder: bool) -> Self {
+        self.distribute_remainder = distribute_remainder;
+        self
     }
-}
-
-impl StatefulWidget for Table<'_> {
-    type State = TableState;
-
-    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
-
-        let table_area = self.render_block(area, buf);
-        if table_area.is_empty() {
-            return;
-        }
-        let selection_width = self.selection_width(state);
-        let columns_widths = self.get_columns_widths(table_area.width, selection_width);
-        let highlight_symbol = self.highlight_symbol.unwrap_or("");
 
-        let (header_area, rows_area) = self.layout(table_area);
-
-        self.render_header(header_area, buf, &columns_widths);
-
-        self.render_rows(
-            rows_area,
-            buf,
-            state,
-            selection_width,
-            highlight_symbol,
-            columns_widths,
-        );
+    /// Wraps the table with a custom [`Block`] widget.
+    ///
+    /// The `block` parameter is of type [`Block`]. This holds the specified block to be
+    /// created around the [`Table`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let block = Block::default().title("Table").borders(Borders::ALL);
+    /// let table = Table::new(rows, widths).block(block);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
     }
-}
 
-// private methods for rendering
-impl Table<'_> {
-    /// Splits the table area into a header and rows area
-    fn layout(&self, area: Rect) -> (Rect, Rect) {
-        let header_height = self.header.as_ref().map_or(0, |h| h.height_with_margin());
-        let layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Length(header_height), Constraint::Min(0)])
-            .split(area);
-        let (header_area, rows_area) = (layout[0], layout[1]);
-        (header_area, rows_area)
+    /// Sets the base style of the widget
+    ///
+    /// All text rendered by the widget will use this style, unless overridden by [`Block::style`],
+    /// [`Row::style`], [`Cell::style`], or the styles of cell's content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).style(Style::new().red().italic());
+    /// ```
+    ///
+    /// `Table` also implements the [`Styled`] trait, which means you can use style shorthands from
+    /// the [`Stylize`] trait to set the style of the widget more concisely.
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = vec![Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).red().italic();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
     }
 
-    fn render_block(&mut self, area: Rect, buf: &mut Buffer) -> Rect {
-        if let Some(block) = self.block.take() {
-            let inner_area = block.inner(area);
-            block.render(area, buf);
-            inner_area
-        } else {
-            area
-        }
+    /// Set the default style of the cells
+    ///
+    /// This style is applied to the header, the pinned rows and the rows before the [`Row::style`]
+    /// and [`Cell::style`], on top of the base style of the widget. Unlike [`Table::style`], it
+    /// does not paint the whole area of the widget, which allows the [`Block`] and the empty space
+    /// around the rows to be styled differently from the content of the cells.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .style(Style::new().on_blue())
+    ///     .cell_style(Style::new().yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_style(mut self, style: Style) -> Self {
+        self.cell_style = style;
+        self
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
-        if let Some(ref header) = self.header {
-            buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
-        }
+    /// Sets the style of each column
+    ///
+    /// The styles are applied to the columns in order, in both the header and the rows. They are
+    /// layered on top of the base and [`Row::style`] styles, and beneath the [`Cell::style`] and
+    /// the styles of the cell's content. Columns without a matching style are left unstyled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Name", "Status"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// // color the status column green
+    /// let table = Table::new(rows, widths).column_styles([Style::new(), Style::new().green()]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_styles<T>(mut self, styles: T) -> Self
+    where
+        T: IntoIterator<Item = Style>,
+    {
+        self.column_styles = styles.into_iter().collect();
+        self
     }
 
-    fn render_rows(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        state: &mut TableState,
-        selection_width: u16,
-        highlight_symbol: &str,
-        columns_widths: Vec<(u16, u16)>,
-    ) {
-        if self.rows.is_empty() {
-            return;
-        }
-
-        let (start_index, end_index) =
-            self.get_row_bounds(state.selected, state.offset, area.height);
-        state.offset = start_index;
-
-        let mut y_offset = 0;
-        for (i, row) in self
-            .rows
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(end_index - start_index)
-        {
-            let row_area = Rect::new(
-                area.x,
-                area.y + y_offset,
-                area.width,
-                row.height_with_margin(),
-            );
-            buf.set_style(row_area, row.style);
-
-            let is_selected = state.selected().is_some_and(|index| index == i);
-            if selection_width > 0 && is_selected {
-                // this should in normal cases be safe, because "get_columns_widths" allocates
-                // "highlight_symbol.width()" space but "get_columns_widths"
-                // currently does not bind it to max table.width()
-                buf.set_stringn(
-                    row_area.x,
-                    row_area.y,
-                    highlight_symbol,
-                    area.width as usize,
-                    row.style,
-                );
-            };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
-            }
-            if is_selected {
-                buf.set_style(row_area, self.highlight_style);
-            }
-            y_offset += row.height_with_margin();
-        }
+    /// Sets alternating background styles of the columns, e.g. to tint every other column
+    ///
+    /// The first column is styled with `even`, the second with `odd`, and so on, in both the
+    /// header and the rows. The columns are counted from the first column of the table, including
+    /// the hidden and skipped columns, so that a column keeps its tint when the table is scrolled
+    /// horizontally. The bands are layered beneath the [`Row::style`] and the
+    /// [`Table::row_decorator`] styles, so that e.g. a striped row is patched over the bands, and
+    /// beneath the [`Table::column_styles`] and the [`Cell::style`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["1", "2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .alternating_column_styles(Style::new(), Style::new().on_dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alternating_column_styles(mut self, even: Style, odd: Style) -> Self {
+        self.alternating_column_styles = Some((even, odd));
+        self
     }
 
-    /// Get all offsets and widths of all user specified columns.
+    /// Set the style of the columns highlighted with [`TableState::set_column_range`]
     ///
-    /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
-    /// and a default of equal widths is returned.
-    fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
-            let col_count = self
-                .rows
-                .iter()
-                .chain(self.header.iter())
-                .map(|r| r.cells.len())
-                .max()
-                .unwrap_or(0);
-            // There are `col_count - 1` spaces between the columns
-            let total_space =
-                max_width.saturating_sub(self.column_spacing * col_count.saturating_sub(1) as u16);
-            // Divide the remaining space between each column equally
-            vec![Constraint::Length(total_space / col_count.max(1) as u16); col_count]
-        } else {
-            self.widths.to_vec()
-        };
-        let constraints = iter::once(Constraint::Length(selection_width))
-            .chain(Itertools::intersperse(
-                widths.iter().cloned(),
-                Constraint::Length(self.column_spacing),
-            ))
-            .collect_vec();
-        let layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(constraints)
-            .segment_size(self.segment_size)
-            .split(Rect::new(0, 0, max_width, 1));
-        layout
-            .iter()
-            .skip(1) // skip selection column
-            .step_by(2) // skip spacing between columns
-            .map(|c| (c.x, c.width))
-            .collect()
+    /// The style is patched over the header and the rows of the highlighted columns, including the
+    /// style of the selected row. When only some of the columns are rendered with
+    /// [`Table::render_rows_only`], the range still refers to the indices of all the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Q1", "Q2", "Q3"])];
+    /// # let widths = [Constraint::Length(5); 3];
+    /// let table = Table::new(rows, widths).column_range_style(Style::new().on_blue());
+    /// let mut state = TableState::new();
+    /// state.set_column_range(Some((1, 2)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_range_style(mut self, style: Style) -> Self {
+        self.column_range_style = style;
+        self
     }
 
-    fn get_row_bounds(
-        &self,
-        selected: Option<usize>,
-        offset: usize,
-        max_height: u16,
-    ) -> (usize, usize) {
-        let offset = offset.min(self.rows.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
-        let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
-                break;
-            }
-            height += item.height_with_margin();
-            end += 1;
-        }
-
-        let selected = selected.unwrap_or(0).min(self.rows.len() - 1);
-        while selected >= end {
-            height = height.saturating_add(self.rows[end].height_with_margin());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.rows[start].height_with_margin());
-                start += 1;
-            }
-        }
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.rows[start].height_with_margin());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.rows[end].height_with_margin());
-            }
-        }
-        (start, end)
+    /// Sets which columns are displayed
+    ///
+    /// Each value of `mask` tells whether the column at the same position is displayed. The
+    /// columns without a matching value are displayed. The hidden columns take no space, not even
+    /// the spacing after them, so the space they would have taken is given to the other columns.
+    ///
+    /// The columns keep their position in the [`Table::widths`], the [`Table::column_styles`] and
+    /// the cells of the rows: hiding a column does not shift the following ones.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Name", "Size", "Date"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5), Constraint::Length(5)];
+    /// // hide the size column
+    /// let table = Table::new(rows, widths).visible_columns([true, false, true]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn visible_columns<T>(mut self, mask: T) -> Self
+    where
+        T: IntoIterator<Item = bool>,
+    {
+        self.visible_columns = mask.into_iter().collect();
+        self
     }
 
-    /// Returns the width of the selection column if a row is selected, or the highlight_spacing is
-    /// set to show the column always, otherwise 0.
-    fn selection_width(&self, state: &TableState) -> u16 {
-        let has_selection = state.selected().is_some();
-        if self.highlight_spacing.should_add(has_selection) {
-            self.highlight_symbol.map_or(0, UnicodeWidthStr::width) as u16
-        } else {
-            0
-        }
+    /// Sets the order in which the columns are laid out
+    ///
+    /// With [`HorizontalDirection::RightToLeft`], the first column is on the right edge of the
+    /// table and the following columns are on its left, and the selection column is on the right
+    /// of the first column. The columns keep their position in the [`Table::widths`], the
+    /// [`Table::column_styles`] and the cells of the rows. The content of the cells is not
+    /// reversed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).direction(HorizontalDirection::RightToLeft);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn direction(mut self, direction: HorizontalDirection) -> Self {
+        self.direction = direction;
+        self
     }
-}
-
-fn ensure_percentages_less_than_100(widths: &[Constraint]) {
-    widths.iter().for_each(|&w| {
-        if let Constraint::Percentage(p) = w {
-            assert!(
-                p <= 100,
-                "Percentages should be between 0 and 100 inclusively."
-            )
-        }
-    });
-}
 
-impl<'a> Styled for Table<'a> {
-    type Item = Table<'a>;
-
-    fn style(&self) -> Style {
-        self.style
+    /// Set a function computing an additional style for each row
+    ///
+    /// The function is called for every visible row with the index of the row in the table and the
+    /// row itself. The returned style is layered on top of the [`Row::style`] before the cells are
+    /// rendered, so the style of the cells and of their content still apply. The highlight style
+    /// of the selected row is layered on top of it.
+    ///
+    /// This is useful to style rows based on the application state without styling every row
+    /// beforehand, e.g. to dim the rows that are disabled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let disabled = [0, 3];
+    /// let table = Table::new(rows, widths).row_decorator(move |index, _row| {
+    ///     if disabled.contains(&index) {
+    ///         Style::new().dim()
+    ///     } else {
+    ///         Style::new()
+    ///     }
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_decorator<F>(mut self, decorator: F) -> Self
+    where
+        F: Fn(usize, &Row) -> Style + Send + Sync + 'a,
+    {
+        self.row_decorator = Some(SharedFn(Arc::new(decorator)));
+        self
     }
 
-    fn set_style(self, style: Style) -> Self::Item {
-        self.style(style)
+    /// Set a function deciding which rows are displayed
+    ///
+    /// The function is called with the index of each row in the table and the row itself, and
+    /// the rows for which it returns `false` are not displayed. This is useful to filter the rows
+    /// of a table, e.g. while the user types a query, without rebuilding the rows.
+    ///
+    /// The rows are filtered before anything else when rendering, so the table behaves as if it
+    /// only had the displayed rows: the [`TableState::selected`] and [`TableState::offset`], the
+    /// indices given to the [`Table::row_decorator`] and [`Table::expanded_row_height`] functions,
+    /// and the indices returned by [`Table::visible_rows`] are positions among the displayed rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let fruits = ["apple", "banana", "cherry"];
+    /// let rows = fruits.map(|fruit| Row::new(vec![fruit]));
+    /// let query = "an";
+    /// let table = Table::new(rows, [Constraint::Length(10)])
+    ///     .filter(|index, _row| fruits[index].contains(query));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(usize, &Row) -> bool + Send + Sync + 'a,
+    {
+        self.filter = Some(SharedFn(Arc::new(filter)));
+        self
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::vec;
-
-    use super::*;
-    use crate::{
-        layout::Constraint::*,
-        style::{Color, Modifier, Style, Stylize},
-        text::Line,
-        widgets::Borders,
-    };
 
-    #[test]
-    fn new() {
-        let rows = [Row::new(vec![Cell::from("")])];
-        let widths = [Constraint::Percentage(100)];
-        let table = Table::new(rows.clone(), widths);
-        assert_eq!(table.rows, rows);
-        assert_eq!(table.widths, widths);
+    /// Set a function computing a summary line, rendered instead of the table in a tiny area
+    ///
+    /// When the area of the table, inside its block, is less than 2 lines high, the header and
+    /// the rows can't be meaningfully displayed. The function is then called with the displayed
+    /// rows and the line it returns is rendered instead, e.g. the number of rows. By default,
+    /// the part of the table that fits in the area is rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(vec!["Cell1"]), Row::new(vec!["Cell2"])];
+    /// let table = Table::new(rows, [Constraint::Length(5)])
+    ///     .collapse_when_tiny(|rows| Line::from(format!("{} items", rows.len())));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn collapse_when_tiny<F>(mut self, summary: F) -> Self
+    where
+        F: Fn(&[Row<'a>]) -> Line<'a> + Send + Sync + 'a,
+    {
+        self.collapsed_summary = Some(SharedFn(Arc::new(summary)));
+        self
     }
 
-    #[test]
-    fn widths() {
-        let table = Table::default().widths([Constraint::Length(100)]);
-        assert_eq!(table.widths, [Constraint::Length(100)]);
-
-        #[allow(clippy::needless_borrows_for_generic_args)]
-        let table = Table::default().widths(&[Constraint::Length(100)]);
-        assert_eq!(table.widths, [Constraint::Length(100)]);
-
-        let table = Table::default().widths(vec![Constraint::Length(100)]);
-        assert_eq!(table.widths, [Constraint::Length(100)]);
-
-        let table = Table::default().widths(&vec![Constraint::Length(100)]);
-        assert_eq!(table.widths, [Constraint::Length(100)]);
-
-        let table = Table::default().widths([100].into_iter().map(Constraint::Length));
-        assert_eq!(table.widths, [Constraint::Length(100)]);
+    /// Set a function computing the height of the expanded rows
+    ///
+    /// The function is called with the index of each row in the table and returns the height of
+    /// the row when it is expanded, or `None` to keep its normal height. The cells are rendered in
+    /// the normal height of the row, and the extra lines display the [`Row::detail`] of the row.
+    /// This can be used to build accordion like master-detail views, by expanding the selected
+    /// row. The expanded height is taken into account to scroll the table and keep the selected
+    /// row visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let rows = [
+    ///     Row::new(vec!["Cell1", "Cell2"]).detail("detail 1\ndetail 2"),
+    ///     Row::new(vec!["Cell3", "Cell4"]).detail("detail 3\ndetail 4"),
+    /// ];
+    /// let state = TableState::default().with_selected(0);
+    /// let selected = state.selected();
+    /// let table = Table::new(rows, widths)
+    ///     .expanded_row_height(move |index| (Some(index) == selected).then_some(3));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expanded_row_height<F>(mut self, expanded_row_height: F) -> Self
+    where
+        F: Fn(usize) -> Option<u16> + Send + Sync + 'a,
+    {
+        self.expanded_row_height = Some(SharedFn(Arc::new(expanded_row_height)));
+        self
     }
 
-    #[test]
-    fn rows() {
-        let rows = [Row::new(vec![Cell::from("")])];
-        let table = Table::default().rows(rows.clone());
-        assert_eq!(table.rows, rows);
+    /// Set the width of the zone at the start of the first column that expands a row when clicked
+    ///
+    /// This is the zone of e.g. an expand chevron (`▸`) displayed in the first cell of the rows,
+    /// which is tested by [`Table::expand_hit`] to tell a click toggling the expansion of a row,
+    /// along with [`Table::expanded_row_height`], from a click selecting it.
+    ///
+    /// Defaults to `0`, which disables the zone.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let widths = [Constraint::Length(7), Constraint::Length(5)];
+    /// let rows = [Row::new(vec!["▸ Cell1", "Cell2"]).detail("detail")];
+    /// let table = Table::new(rows, widths).expand_zone_width(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expand_zone_width(mut self, width: u16) -> Self {
+        self.expand_zone_width = width;
+        self
     }
 
-    #[test]
-    fn column_spacing() {
-        let table = Table::default().column_spacing(2);
-        assert_eq!(table.column_spacing, 2);
+    /// Set the style of the row under the mouse cursor
+    ///
+    /// This style is applied to the row set with [`TableState::set_hovered`]. It is layered
+    /// beneath the [`Table::highlight_style`], so the selection highlight wins when the hovered
+    /// row is also selected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).hover_style(Style::new().on_dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hover_style(mut self, hover_style: Style) -> Self {
+        self.hover_style = hover_style;
+        self
     }
 
-    #[test]
-    fn block() {
-        let block = Block::default().title("Table").borders(Borders::ALL);
-        let table = Table::default().block(block.clone());
-        assert_eq!(table.block, Some(block));
+    /// Set the style of the selected row
+    ///
+    /// This style will be applied to the entire row, including the selection symbol if it is
+    /// displayed, and will override any style set on the row or on the individual cells.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_style(Style::new().red().italic());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, highlight_style: Style) -> Self {
+        self.highlight_style = highlight_style;
+        self
     }
 
-    #[test]
-    fn header() {
-        let header = Row::new(vec![Cell::from("")]);
-        let table = Table::default().header(header.clone());
-        assert_eq!(table.header, Some(header));
+    /// Set the foreground color of the selected row
+    ///
+    /// This changes the foreground color of the [`Table::highlight_style`], keeping its other
+    /// properties.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_fg(Color::Yellow);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_fg(mut self, color: Color) -> Self {
+        self.highlight_style = self.highlight_style.fg(color);
+        self
     }
 
-    #[test]
-    fn highlight_style() {
-        let style = Style::default().red().italic();
-        let table = Table::default().highlight_style(style);
-        assert_eq!(table.highlight_style, style);
+    /// Set the background color of the selected row
+    ///
+    /// This changes the background color of the [`Table::highlight_style`], keeping its other
+    /// properties.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_bg(Color::Blue);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_bg(mut self, color: Color) -> Self {
+        self.highlight_style = self.highlight_style.bg(color);
+        self
     }
 
-    #[test]
-    fn highlight_symbol() {
-        let table = Table::default().highlight_symbol(">>");
-        assert_eq!(table.highlight_symbol, Some(">>"));
-    }
+    /// Add a modifier to the selected row
+    ///
+    /// This adds the modifier to the [`Table::highlight_style`], keeping its other properties and
+    /// modifiers.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_modifier(Modifier::BOLD);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_modifier(mut self, modifier: Modifier) -> Self {
+        self.highlight_style = self.highlight_style.add_modifier(modifier);
+        self
+    }
 
-    #[test]
-    fn highlight_spacing() {
-        let table = Table::default().highlight_spacing(HighlightSpacing::Always);
-        assert_eq!(table.highlight_spacing, HighlightSpacing::Always);
+    /// Set the symbol to be displayed in front of the selected row
+    ///
+    /// The `highlight_symbol` parameter accepts any value that can be converted into a
+    /// [`Cow<str>`], i.e. a borrowed `&str` or an owned [`String`] (e.g. built from a
+    /// configuration file).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_symbol(">>");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol<S>(mut self, highlight_symbol: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.highlight_symbol = Some(highlight_symbol.into());
+        self
     }
 
-    #[test]
-    #[should_panic]
-    fn table_invalid_percentages() {
-        let _ = Table::default().widths([Constraint::Percentage(110)]);
+    /// Set the style of the selection column of the selected row
+    ///
+    /// This style is applied only to the area reserved for the highlight symbol, on top of the
+    /// [`Table::highlight_style`] that is applied to the whole row. This allows the selection
+    /// column (e.g. an arrow) to have a background distinct from the rest of the selected row. The
+    /// style is patched onto the existing style, so the symbol keeps any property that is not set
+    /// by this style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol(">>")
+    ///     .highlight_style(Style::new().on_blue())
+    ///     .highlight_symbol_style(Style::new().yellow().on_red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol_style(mut self, style: Style) -> Self {
+        self.highlight_symbol_style = style;
+        self
     }
 
-    #[test]
-    fn widths_conversions() {
-        let array = [Constraint::Percentage(100)];
-        let table = Table::new(vec![], array);
-        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "array");
+    /// Set whether the highlight symbol is drawn with the highlight style instead of the row style
+    ///
+    /// By default, the highlight symbol is drawn with the [`Row::style`] of the selected row, on
+    /// top of which the [`Table::highlight_style`] is patched, so a dim row also dims the symbol.
+    /// When enabled, the selection column of the selected row is drawn with the style of the table
+    /// and the [`Table::highlight_style`] only, so that the symbol stands out. The
+    /// [`Table::highlight_symbol_style`] is still patched on top.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"]).dim()];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol(">>")
+    ///     .highlight_style(Style::new().yellow())
+    ///     .highlight_symbol_use_highlight_style(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol_use_highlight_style(mut self, use_highlight_style: bool) -> Self {
+        self.highlight_symbol_use_highlight_style = use_highlight_style;
+        self
+    }
 
-        let array_ref = &[Constraint::Percentage(100)];
-        let table = Table::new(vec![], array_ref);
-        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "array ref");
+    /// Set whether the highlight symbol is drawn on every line of the selected row
+    ///
+    /// By default the highlight symbol is only drawn on the first line of the selected row. When
+    /// rows are taller than one line (see [`Row::height`]), this draws the symbol on each of its
+    /// lines so that the whole row is clearly marked.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"]).height(3)];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol(">>")
+    ///     .highlight_symbol_repeat(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol_repeat(mut self, repeat: bool) -> Self {
+        self.highlight_symbol_repeat = repeat;
+        self
+    }
+
+    /// Highlight the parts of the content of the cells matching a search query
+    ///
+    /// When the rows are rendered, every occurrence of `query` in a line of a cell is patched with
+    /// `style`, on top of the style of its content. The occurrences are found from left to right
+    /// and do not overlap, e.g. `"aa"` matches `"aaa"` only once. A match can span several spans of
+    /// the line, and a match that is partially hidden by the truncation of the cell is highlighted
+    /// on its visible part. The header is not highlighted. An empty query highlights nothing.
+    ///
+    /// By default, the query is case sensitive, see [`Table::highlight_matches_ignore_case`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_matches("ell", Style::new().on_yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_matches<Q>(mut self, query: Q, style: Style) -> Self
+    where
+        Q: Into<Cow<'a, str>>,
+    {
+        self.match_query = Some(query.into());
+        self.match_style = style;
+        self
+    }
+
+    /// Set whether the query of [`Table::highlight_matches`] ignores case
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_matches("CELL", Style::new().on_yellow())
+    ///     .highlight_matches_ignore_case(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_matches_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.match_ignore_case = ignore_case;
+        self
+    }
+
+    /// Set when to show the highlight spacing
+    ///
+    /// The highlight spacing is the spacing that is allocated for the selection symbol column (if
+    /// enabled) and is used to shift the table when a row is selected. This method allows you to
+    /// configure when this spacing is allocated.
+    ///
+    /// - [`HighlightSpacing::Always`] will always allocate the spacing, regardless of whether a row
+    ///   is selected or not. This means that the table will never change size, regardless of if a
+    ///   row is selected or not.
+    /// - [`HighlightSpacing::WhenSelected`] will only allocate the spacing if a row is selected.
+    ///   This means that the table will shift when a row is selected. This is the default setting
+    ///   for backwards compatibility, but it is recommended to use `HighlightSpacing::Always` for a
+    ///   better user experience.
+    /// - [`HighlightSpacing::Never`] will never allocate the spacing, regardless of whether a row
+    ///   is selected or not. This means that the highlight symbol will never be drawn.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_spacing(HighlightSpacing::Always);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
+        self.highlight_spacing = value;
+        self
+    }
+
+    /// Set the highlight symbol, style and spacing from a [`HighlightTheme`]
+    ///
+    /// This is a shorthand for calling [`Table::highlight_symbol`], [`Table::highlight_style`]
+    /// and [`Table::highlight_spacing`], so that the same selection look can be applied to many
+    /// tables.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let theme = HighlightTheme {
+    ///     symbol: ">> ".into(),
+    ///     style: Style::new().yellow(),
+    ///     spacing: HighlightSpacing::Always,
+    /// };
+    /// let table = Table::new(rows, widths).highlight_theme(theme);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_theme(self, theme: HighlightTheme<'a>) -> Self {
+        self.highlight_symbol(theme.symbol)
+            .highlight_style(theme.style)
+            .highlight_spacing(theme.spacing)
+    }
+
+    /// Set which edge of the table the rows are attached to
+    ///
+    /// By default the rows fill the table from the top ([`Anchor::Top`]). With [`Anchor::Bottom`],
+    /// the rows fill the table from the bottom upward: when they do not fill the table they are
+    /// displayed at its bottom, and when the last row is displayed the rows above it fill the
+    /// table. Combined with [`TableState::set_auto_follow`] this creates a log or chat view where
+    /// the newest row is at the bottom.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).anchor(Anchor::Bottom);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Set what happens when the selected row is past the last row
+    ///
+    /// This commonly happens when the rows are refreshed with fewer rows than before. By default
+    /// the selection is clamped to the last row ([`InvalidSelection::Clamp`]). With
+    /// [`InvalidSelection::Clear`], the selection of the state is cleared when rendering. With
+    /// [`InvalidSelection::KeepOffset`], the selection of the state is left as is and the rows are
+    /// not scrolled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).on_invalid_selection(InvalidSelection::Clear);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_invalid_selection(mut self, invalid_selection: InvalidSelection) -> Self {
+        self.invalid_selection = invalid_selection;
+        self
+    }
+
+    /// Force the width reserved for the selection column
+    ///
+    /// By default the selection column is as wide as the highlight symbol, as measured by
+    /// [`UnicodeWidthStr::width`]. Some symbols (e.g. emoji and ZWJ sequences) are rendered wider
+    /// by some terminals than they are measured, which causes the columns to overlap the symbol.
+    /// When set, this width takes precedence over the measured width of the symbol.
+    ///
+    /// The [`HighlightSpacing`] still decides whether the selection column is allocated at all.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol("➡")
+    ///     .selection_width_override(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_width_override(mut self, width: u16) -> Self {
+        self.selection_width_override = Some(width);
+        self
+    }
+
+    /// Set whether East Asian ambiguous width characters are measured as double width
+    ///
+    /// Some characters (e.g. `→`, `①` or `○`) have an ambiguous width: most terminals render them
+    /// as a single cell, but terminals configured for CJK locales render them as two cells. When
+    /// enabled, the highlight symbol and the content of the cells are measured with
+    /// [`UnicodeWidthStr::width_cjk`], so that the selection column is wide enough and the cells
+    /// are aligned and truncated to fit their columns on these terminals. Use
+    /// [`Table::selection_width_override`] to set the width of the selection column explicitly.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol("→")
+    ///     .east_asian_width(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn east_asian_width(mut self, enabled: bool) -> Self {
+        self.east_asian_width = enabled;
+        self
+    }
+
+    /// Set the minimum width of a displayed column
+    ///
+    /// When the available width is too small to satisfy the constraints, the layout can shrink
+    /// columns down to a few cells or even to nothing, which hides their content. Columns that are
+    /// allocated less than `width` are widened to `width`, shifting the following columns to the
+    /// right. Trailing columns that no longer fit in the table are dropped entirely rather than
+    /// being displayed as slivers.
+    ///
+    /// The floor is applied after the space has been distributed according to [`Table::flex`], so
+    /// the extra space given to the columns counts towards the floor.
+    ///
+    /// Defaults to `0`, which leaves the allocated widths untouched.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).min_column_width(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_column_width(mut self, width: u16) -> Self {
+        self.min_column_width = width;
+        self
+    }
+
+    /// Only draw the rows that changed since the last render
+    ///
+    /// When enabled, the rows are only drawn if they were marked with
+    /// [`TableState::mark_row_dirty`] since the last render, and the other rows are left untouched
+    /// in the buffer. This avoids redrawing every visible row when only a few cells change on each
+    /// frame. All the rows are drawn on the first render, after [`TableState::mark_all_dirty`],
+    /// and whenever the area, the selection or the offset of the table changed since the last
+    /// render. Call [`TableState::mark_all_dirty`] when rows are added or removed.
+    ///
+    /// This only works when the buffer keeps the content of the previous render. By default the
+    /// buffer of each [`Frame`] starts empty, so the rows that are not dirty disappear: enable
+    /// [`Terminal::set_retain_buffer`] when rendering with [`Terminal::draw`], or render into a
+    /// [`Buffer`] kept across renders.
+    ///
+    /// [`Terminal::set_retain_buffer`]: crate::Terminal::set_retain_buffer
+    /// [`Terminal::draw`]: crate::Terminal::draw
+    ///
+    /// Defaults to `false`, which draws all the visible rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).incremental(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn incremental(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    /// Set the maximum number of rows displayed at once
+    ///
+    /// The table displays at most `max_visible_rows` rows, even when the area is tall enough to
+    /// display more of them. The remaining space below the rows is left blank. This is useful for
+    /// dashboards where tables must keep a consistent size. The selected row is still scrolled
+    /// into view within these rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).max_visible_rows(5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_visible_rows(mut self, max_visible_rows: u16) -> Self {
+        self.max_visible_rows = Some(max_visible_rows);
+        self
+    }
+
+    /// Set how extra space is distributed amongst columns.
+    ///
+    /// This determines how the space is distributed when the constraints are satisfied. By default,
+    /// the extra space is not distributed at all ([`Flex::None`]). But this can be changed to
+    /// distribute all extra space to the last column ([`Flex::LastTakesRemainder`]).
+    ///
+    /// This replaces the deprecated [`Table::segment_size`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// Create a table that needs at least 30 columns to display.  Any extra space will be assigned
+    /// to the last column.
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Flex, prelude::*, widgets::*};
+    /// let widths = [
+    ///     Constraint::Min(10),
+    ///     Constraint::Min(10),
+    ///     Constraint::Min(10),
+    /// ];
+    /// let table = Table::new([], widths).flex(Flex::LastTakesRemainder);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn flex(mut self, flex: Flex) -> Self {
+        self.segment_size = flex.into();
+        self
+    }
+
+    /// Set how extra space is distributed amongst columns.
+    ///
+    /// This determines how the space is distributed when the constraints are satisfied. By default,
+    /// the extra space is not distributed at all.  But this can be changed to distribute all extra
+    /// space to the last column or to distribute it equally.
+    ///
+    /// The stable ways of distributing the space are forwarded to [`Table::flex`], which should be
+    /// used instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// Create a table that needs at least 30 columns to display.  Any extra space will be assigned
+    /// to the last column.
+    #[cfg_attr(feature = "unstable", doc = " ```")]
+    #[cfg_attr(not(feature = "unstable"), doc = " ```ignore")]
+    /// # use ratatui::layout::Constraint;
+    /// # use ratatui::layout::SegmentSize;
+    /// # use ratatui::widgets::Table;
+    /// let widths = [Constraint::Min(10), Constraint::Min(10), Constraint::Min(10)];
+    /// let table = Table::new([], widths)
+    ///     .segment_size(SegmentSize::LastTakesRemainder);
+    /// ```
+    #[stability::unstable(
+        feature = "segment-size",
+        reason = "The name for this feature is not final and may change in the future",
+        issue = "https://github.com/ratatui-org/ratatui/issues/536"
+    )]
+    #[deprecated(note = "use Table::flex")]
+    pub fn segment_size(mut self, segment_size: SegmentSize) -> Self {
+        match segment_size {
+            SegmentSize::LastTakesRemainder => self.flex(Flex::LastTakesRemainder),
+            SegmentSize::None => self.flex(Flex::None),
+            SegmentSize::EvenDistribution => {
+                self.segment_size = segment_size;
+                self
+            }
+        }
+    }
+
+    /// Set a function laying out the columns instead of the built-in layout
+    ///
+    /// This gives complete control over how the constraints map to the widths of the columns, as
+    /// an escape hatch for sizing rules that [`Table::flex`] can't express (e.g. shrinking the
+    /// widest column first). The function is called with the width available to the columns, the
+    /// constraints of the displayed columns and the spacing between them, and returns the `(x,
+    /// width)` of each of these columns, `x` being relative to the start of the available width.
+    ///
+    /// The returned `x` values must be monotonically increasing and the columns must stay within
+    /// the available width. The selection column, the [`Table::edge_padding`], the
+    /// [`Table::min_column_width`], the hidden columns and the [`Table::direction`] are still
+    /// handled by the table. The widths are computed on every render instead of being reused from
+    /// the previous render.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// Split the available width equally between the columns:
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let widths = [Constraint::Min(5), Constraint::Min(5)];
+    /// let table = Table::new([], widths).width_resolver(|available, constraints, spacing| {
+    ///     let count = constraints.len() as u16;
+    ///     let spacers = spacing * count.saturating_sub(1);
+    ///     let width = available.saturating_sub(spacers) / count.max(1);
+    ///     (0..count).map(|i| (i * (width + spacing), width)).collect()
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn width_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(u16, &[Constraint], u16) -> Vec<(u16, u16)> + Send + Sync + 'a,
+    {
+        self.width_resolver = Some(SharedFn(Arc::new(resolver)));
+        self
+    }
+
+    /// Returns each column constraint paired with the width it receives when rendered in `area`
+    ///
+    /// This is a debugging helper to understand why columns are not sized as expected (e.g. why a
+    /// [`Constraint::Min`] column collapsed). The widths are computed the same way as when the
+    /// table is rendered without any selected row: the inset of the [`Block`] and the selection
+    /// column (when [`HighlightSpacing::Always`] is used) are taken into account. A column that is
+    /// not displayed at all receives a width of `0`.
+    ///
+    /// When [`Table::widths`] was not called, the constraints are the equal widths computed by
+    /// the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let widths = [Constraint::Length(5), Constraint::Min(10)];
+    /// let table = Table::new([Row::new(vec!["Cell1", "Cell2"])], widths);
+    /// let explained = table.explain_widths(Rect::new(0, 0, 20, 1));
+    /// assert_eq!(
+    ///     explained,
+    ///     [(Constraint::Length(5), 5), (Constraint::Min(10), 10)]
+    /// );
+    /// ```
+    pub fn explain_widths(&self, area: Rect) -> Vec<(Constraint, u16)> {
+        let table_area = self.block.as_ref().map_or(area, |block| block.inner(area));
+        let (_, _, rows_area, _) = self.layout(table_area);
+        let selection_width = self.selection_width(&TableState::default());
+        let widths = self.get_columns_widths(rows_area.width, selection_width);
+        self.column_constraints(table_area.width)
+            .into_iter()
+            .enumerate()
+            .map(|(i, constraint)| (constraint, widths.get(i).map_or(0, |&(_, width)| width)))
+            .collect()
+    }
+
+    /// Returns the minimum `(width, height)` needed to display all the columns and the first
+    /// `visible_rows` rows without truncation
+    ///
+    /// The width is the sum of the [`Constraint::Length`] and [`Constraint::Min`] widths, the
+    /// spacing between the columns and the width of the selection column when a row is selected.
+    /// Other constraints can shrink to nothing and do not add to the width. The height is the
+    /// height of the header, the pinned rows, the first `visible_rows` rows and the caption,
+    /// including their margins. Both include the borders and padding of the [`Block`].
+    ///
+    /// This is useful to decide how to lay out the parent of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1", "Cell2"]),
+    ///     Row::new(vec!["Cell3", "Cell4"]),
+    /// ];
+    /// let widths = [Constraint::Length(5), Constraint::Min(10)];
+    /// let table = Table::new(rows, widths).header(Row::new(vec!["Head1", "Head2"]));
+    /// assert_eq!(table.min_size(2), (16, 3));
+    /// ```
+    pub fn min_size(&self, visible_rows: usize) -> (u16, u16) {
+        let columns_width = self
+            .widths
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Length(width) | Constraint::Min(width) => width,
+                _ => 0,
+            })
+            .fold(0, u16::saturating_add);
+        let spacers = self.column_constraints(0).len().saturating_sub(1);
+        let spacing = self
+            .effective_column_spacing(u16::MAX)
+            .saturating_mul(u16::try_from(spacers).unwrap_or(u16::MAX));
+        let selection_width = self.selection_width(&TableState::new().with_selected(0));
+        let width = columns_width
+            .saturating_add(spacing)
+            .saturating_add(selection_width)
+            .saturating_add(self.edge_padding.saturating_mul(2))
+            .saturating_add(self.grid_line_width().saturating_mul(2));
+
+        let height = self
+            .header
+            .iter()
+            .map(|header| self.header_height(header))
+            .chain(
+                self.pinned_rows
+                    .iter()
+                    .map(|row| self.fixed_row_height(row)),
+            )
+            .chain(
+                self.displayed_rows()
+                    .take(visible_rows)
+                    .enumerate()
+                    .map(|(index, row)| self.row_height_of_with_margin(index, row)),
+            )
+            .fold(u16::from(self.caption.is_some()), u16::saturating_add)
+            .saturating_add(self.grid_line_width().saturating_mul(2));
+
+        // the borders and padding of the block surround the table
+        let (block_width, block_height) = self.block.as_ref().map_or((0, 0), |block| {
+            let area = Rect::new(0, 0, 255, 255);
+            let inner = block.inner(area);
+            (area.width - inner.width, area.height - inner.height)
+        });
+        (
+            width.saturating_add(block_width),
+            height.saturating_add(block_height),
+        )
+    }
+
+    /// Returns the style of a cell of the rows, as it is rendered with the given state
+    ///
+    /// The style is computed by patching, in order:
+    ///
+    /// 1. the base style of the table ([`Table::style`])
+    /// 2. the default style of the cells ([`Table::cell_style`])
+    /// 3. the band of the column ([`Table::alternating_column_styles`])
+    /// 4. the style of the row ([`Row::style`])
+    /// 5. the style returned by the [`Table::row_decorator`]
+    /// 6. the style of the column ([`Table::column_styles`])
+    /// 7. the style of the cell ([`Cell::style`])
+    /// 8. the [`Table::hover_style`] if the row is hovered
+    /// 9. the [`Table::highlight_style`] if the row is selected
+    ///
+    /// The styles of the content of the cell (e.g. of its [`Span`]s) are patched between the style
+    /// of the cell and the hover style when rendering, and are not part of the returned style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(vec![Cell::new("Cell1").red()]).on_blue()];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let style = table.resolve_cell_style(&TableState::default(), 0, 0);
+    /// assert_eq!(style, Style::new().red().on_blue());
+    /// ```
+    pub fn resolve_cell_style(
+        &self,
+        state: &TableState,
+        row_index: usize,
+        column_index: usize,
+    ) -> Style {
+        let mut style = self.base_cell_style(row_index, column_index);
+        if state.hovered == Some(row_index) {
+            style = style.patch(self.hover_style);
+        }
+        if state.is_in_selection(row_index) {
+            style = style.patch(self.highlight_style);
+        }
+        style
+    }
+
+    /// Returns whether the table reserves a column for the selection symbol
+    ///
+    /// This follows the [`Table::highlight_spacing`] setting: the column is reserved always, only
+    /// when a row is selected in `state`, or never. Applications drawing custom gutters next to
+    /// the table can use this to stay aligned with the rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default().highlight_spacing(HighlightSpacing::WhenSelected);
+    /// assert!(!table.reserves_selection_column(&TableState::default()));
+    /// assert!(table.reserves_selection_column(&TableState::default().with_selected(0)));
+    /// ```
+    pub fn reserves_selection_column(&self, state: &TableState) -> bool {
+        self.highlight_spacing
+            .should_add(state.selected().is_some())
+    }
+
+    /// Returns the index of the row displayed at the given `y` position by the last render
+    ///
+    /// This is useful to select a row with a mouse click. The position is looked up among the rows
+    /// drawn by the last render of the table with `state`, so it accounts for everything that
+    /// moves the rows: the [`Table::filter`], the [`Table::anchor`], the line offset, the header
+    /// scrolled away, the overflow markers and the [`ScrollMode`]. The table must therefore have
+    /// the same rows as when it was rendered. As the [`TableState::selected`] row, the index is
+    /// counted among the rows displayed by the [`Table::filter`].
+    ///
+    /// Returns `None` when `y` is outside of the area of the rows, lands on the bottom margin of a
+    /// row or below the last row, or when the table was not rendered with `state`.
+    ///
+    /// The area of the rows is not passed in, as it is recorded in `state` by the render, along
+    /// with the rows it drew. Use [`Table::row_at_y_in`] to look up a row in a given area of rows
+    /// without rendering the table first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1"]).height(2).bottom_margin(1),
+    ///     Row::new(vec!["Cell2"]),
+    /// ];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let mut state = TableState::default();
+    /// # let mut buf = Buffer::empty(Rect::new(0, 0, 5, 10));
+    /// StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+    /// assert_eq!(table.row_at_y(&state, 1), Some(0));
+    /// assert_eq!(table.row_at_y(&state, 2), None);
+    /// assert_eq!(table.row_at_y(&state, 3), Some(1));
+    /// ```
+    ///
+    /// [`ScrollMode`]: crate::widgets::ScrollMode
+    pub fn row_at_y(&self, state: &TableState, y: u16) -> Option<usize> {
+        let rows_area = state.rows_area;
+        if y < rows_area.top() || y >= rows_area.bottom() {
+            return None;
+        }
+        let (start, end) = state.visible_rows;
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|&(index, row)| self.is_row_displayed(index, row))
+            .map(|(_, row)| row)
+            .enumerate()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect_vec();
+        // the rows are drawn from `line_offset` lines above the area, as in `render_rows`
+        let drawn_height = rows_area.height.saturating_add(state.line_offset);
+        let mut row_y = match self.anchor {
+            Anchor::Top => 0,
+            Anchor::Bottom => {
+                let rows_height = rows
+                    .iter()
+                    .map(|&(index, row)| self.row_height_of_with_margin(index, row))
+                    .fold(0, u16::saturating_add);
+                drawn_height.saturating_sub(rows_height)
+            }
+        };
+        let y = (y - rows_area.y).saturating_add(state.line_offset);
+        for (index, row) in rows {
+            if y < row_y {
+                break;
+            }
+            if y < row_y.saturating_add(self.row_height_of(index, row)) {
+                return Some(index);
+            }
+            row_y = row_y.saturating_add(self.row_height_of_with_margin(index, row));
+        }
+        None
+    }
+
+    /// Returns the index of the row displayed at the given `y` position in `rows_area`
+    ///
+    /// The rows are walked from the top of `rows_area`, starting at the [`TableState::offset`]
+    /// row and skipping the [`TableState::line_offset`] lines of that row, with their heights and
+    /// bottom margins. Unlike [`Table::row_at_y`], this does not need the table to be rendered with
+    /// `state`, but it only knows about the area given: it does not account for the anchor, the
+    /// header scrolled away, the overflow markers, or the rows scrolled by the render to show the
+    /// selected row. As the [`TableState::selected`] row, the index is counted among the rows
+    /// displayed by the [`Table::filter`].
+    ///
+    /// Returns `None` when `y` is outside of `rows_area`, lands on the bottom margin of a row or
+    /// below the last row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1"]).height(2).bottom_margin(1),
+    ///     Row::new(vec!["Cell2"]),
+    /// ];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let rows_area = Rect::new(0, 1, 5, 4);
+    /// let state = TableState::default();
+    /// assert_eq!(table.row_at_y_in(rows_area, &state, 2), Some(0));
+    /// assert_eq!(table.row_at_y_in(rows_area, &state, 3), None);
+    /// assert_eq!(table.row_at_y_in(rows_area, &state, 4), Some(1));
+    /// ```
+    pub fn row_at_y_in(&self, rows_area: Rect, state: &TableState, y: u16) -> Option<usize> {
+        if y < rows_area.top() || y >= rows_area.bottom() {
+            return None;
+        }
+        let y = (y - rows_area.y).saturating_add(state.line_offset);
+        let mut row_y = 0u16;
+        for (index, row) in self.displayed_rows().enumerate().skip(state.offset) {
+            if y < row_y {
+                break;
+            }
+            if y < row_y.saturating_add(self.row_height_of(index, row)) {
+                return Some(index);
+            }
+            row_y = row_y.saturating_add(self.row_height_of_with_margin(index, row));
+        }
+        None
+    }
+
+    /// Returns the index of the row whose expand zone is displayed at the given position by the
+    /// last render
+    ///
+    /// The expand zone of a row is made of its first [`Table::expand_zone_width`] cells in the
+    /// first displayed column. This is useful to toggle the expansion of a row when the zone is
+    /// clicked, and to select the row, e.g. with [`Table::row_at_y`], when it is clicked
+    /// elsewhere. As with [`Table::row_at_y`], the position is looked up among the rows and the
+    /// columns drawn by the last render of the table with `state`.
+    ///
+    /// Returns `None` when the position is outside of the expand zone of the displayed rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["▸ Cell1", "Cell2"]),
+    ///     Row::new(vec!["▸ Cell3", "Cell4"]),
+    /// ];
+    /// let widths = [Constraint::Length(7), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).expand_zone_width(2);
+    /// let mut state = TableState::default();
+    /// # let mut buf = Buffer::empty(Rect::new(0, 0, 13, 2));
+    /// StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+    /// assert_eq!(table.expand_hit(&state, 1, 1), Some(1));
+    /// assert_eq!(table.expand_hit(&state, 4, 1), None);
+    /// ```
+    pub fn expand_hit(&self, state: &TableState, x: u16, y: u16) -> Option<usize> {
+        if self.expand_zone_width == 0 {
+            return None;
+        }
+        let row = self.row_at_y(state, y)?;
+        let column = state.column_rects.iter().find(|rect| rect.width > 0)?;
+        let zone_width = self.expand_zone_width.min(column.width);
+        (x >= column.x && x < column.x.saturating_add(zone_width)).then_some(row)
+    }
+
+    /// Renders the table in the given area of a buffer, guaranteeing that nothing is written
+    /// outside of that area
+    ///
+    /// This is useful when composing several tables into one buffer manually. The area is first
+    /// clamped to the area of the buffer. In debug builds, this asserts that the cells of the
+    /// buffer outside of the area are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+    /// let left = Table::new([Row::new(vec!["Left"])], [Constraint::Length(5)]);
+    /// let right = Table::new([Row::new(vec!["Right"])], [Constraint::Length(5)]);
+    /// left.render_clipped(Rect::new(0, 0, 10, 2), &mut buf);
+    /// right.render_clipped(Rect::new(10, 0, 10, 2), &mut buf);
+    /// ```
+    pub fn render_clipped(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        #[cfg(debug_assertions)]
+        let before = buf.clone();
+        Widget::render(self, area, buf);
+        #[cfg(debug_assertions)]
+        for y in before.area.top()..before.area.bottom() {
+            for x in before.area.left()..before.area.right() {
+                let inside = (area.left()..area.right()).contains(&x)
+                    && (area.top()..area.bottom()).contains(&y);
+                debug_assert!(
+                    inside || before.get(x, y) == buf.get(x, y),
+                    "the table wrote outside of {area:?} at ({x}, {y})"
+                );
+            }
+        }
+    }
+
+    /// Returns an iterator over the rows that the next render in `area` would draw, along with
+    /// their index
+    ///
+    /// This is useful to prefetch or lazily load the data of only the visible rows. The rows are
+    /// computed the same way as when rendering, taking into account the [`Block`], the header, the
+    /// pinned rows, the caption, the selected row and the [`TableState::offset`], but the state is
+    /// not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = (0..100).map(|i| Row::new(vec![i.to_string()]));
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let state = TableState::default().with_offset(10).with_selected(12);
+    /// let visible = table.visible_rows(Rect::new(0, 0, 5, 3), &state);
+    /// assert_eq!(visible.map(|(i, _)| i).collect::<Vec<_>>(), [10, 11, 12]);
+    /// ```
+    pub fn visible_rows(
+        &self,
+        area: Rect,
+        state: &TableState,
+    ) -> impl Iterator<Item = (usize, &Row<'a>)> {
+        let (start, end) = if self.filter.is_some() {
+            let rows = self.displayed_rows().collect_vec();
+            self.visible_row_bounds(&rows, area, state)
+        } else {
+            self.visible_row_bounds(&self.rows, area, state)
+        };
+        self.displayed_rows().enumerate().take(end).skip(start)
+    }
+
+    /// Selects the next selectable row after the selected row
+    ///
+    /// The rows that are not [`Row::selectable`] are skipped over. When no row is selected, the
+    /// first selectable row is selected. When there is no selectable row after the selected row,
+    /// the selection is unchanged. The rows are counted among the rows displayed by the
+    /// [`Table::filter`], as the [`TableState::selected`] row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1"]),
+    ///     Row::new(vec!["Section 2"]).selectable(false),
+    ///     Row::new(vec!["Cell2"]),
+    /// ];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let mut state = TableState::new().with_selected(0);
+    /// table.select_next(&mut state);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn select_next(&self, state: &mut TableState) {
+        let next = self
+            .selectable_rows()
+            .into_iter()
+            .find(|&index| state.selected.map_or(true, |selected| index > selected));
+        if next.is_some() {
+            state.select(next);
+        }
+    }
+
+    /// Selects the previous selectable row before the selected row
+    ///
+    /// The rows that are not [`Row::selectable`] are skipped over. When no row is selected, the
+    /// last selectable row is selected. When there is no selectable row before the selected row,
+    /// the selection is unchanged. The rows are counted among the rows displayed by the
+    /// [`Table::filter`], as the [`TableState::selected`] row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1"]),
+    ///     Row::new(vec!["Section 2"]).selectable(false),
+    ///     Row::new(vec!["Cell2"]),
+    /// ];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let mut state = TableState::new().with_selected(2);
+    /// table.select_previous(&mut state);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_previous(&self, state: &mut TableState) {
+        let previous = self
+            .selectable_rows()
+            .into_iter()
+            .rev()
+            .find(|&index| state.selected.map_or(true, |selected| index < selected));
+        if previous.is_some() {
+            state.select(previous);
+        }
+    }
+
+    /// Selects the first row matching `predicate` and returns its index
+    ///
+    /// This keeps the same row selected when the rows are rebuilt in another order, e.g. after
+    /// sorting them, by locating the row from its content rather than from its index. The rows are
+    /// counted among the rows displayed by the [`Table::filter`], as the [`TableState::selected`]
+    /// row. When no row matches, the selection is left unchanged and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(vec!["Bob"]), Row::new(vec!["Alice"])];
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let mut state = TableState::new();
+    /// let index = table.select_by(&mut state, |row| {
+    ///     row.cell_text(0).as_deref() == Some("Alice")
+    /// });
+    /// assert_eq!(index, Some(1));
+    /// assert_eq!(state.selected(), Some(1));
+    /// ```
+    pub fn select_by<F>(&self, state: &mut TableState, predicate: F) -> Option<usize>
+    where
+        F: Fn(&Row) -> bool,
+    {
+        let index = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|&(index, row)| self.is_row_displayed(index, row))
+            .position(|(_, row)| predicate(row));
+        if index.is_some() {
+            state.select(index);
+        }
+        index
+    }
+
+    /// Exports the header, pinned rows and rows of the table as plain text, e.g. as CSV or TSV
+    ///
+    /// Each row is written on its own line, with the text of its cells separated by `sep`. The
+    /// styles are dropped, the lines of a multi-line cell are joined with a space, and a cell
+    /// containing `sep` or a `"` is quoted, with its `"` doubled, as in CSV. The rows hidden by
+    /// the [`Table::filter`] and the columns hidden by [`Table::visible_columns`] are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::new(
+    ///     [Row::new(vec!["Apple", "1,50"])],
+    ///     [Constraint::Length(5), Constraint::Length(5)],
+    /// )
+    /// .header(Row::new(vec!["Name", "Price"]));
+    /// assert_eq!(table.to_delimited(','), "Name,Price\nApple,\"1,50\"");
+    /// assert_eq!(table.to_delimited('\t'), "Name\tPrice\nApple\t1,50");
+    /// ```
+    pub fn to_delimited(&self, sep: char) -> String {
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|&(index, row)| self.is_row_displayed(index, row))
+            .map(|(_, row)| row);
+        self.header
+            .iter()
+            .chain(self.pinned_rows.iter())
+            .chain(rows)
+            .map(|row| {
+                row.cells
+                    .iter()
+                    .enumerate()
+                    .filter(|&(column, _)| self.is_column_visible(column))
+                    .map(|(_, cell)| delimited_field(&cell.plain_text(), sep))
+                    .collect::<Vec<_>>()
+                    .join(&sep.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders only the header of the table, starting from the column at `column_offset`
+    ///
+    /// The columns before `column_offset` are left out, and the following columns are laid out in
+    /// `area` as when rendering the whole table. Along with [`Table::render_rows_only`], this lets
+    /// the pieces of a table be composed manually, e.g. to split a wide table across two panes
+    /// below a header spanning both of them.
+    ///
+    /// The [`Block`] and the caption are not rendered. As no row is selected, the selection column
+    /// is only reserved with [`HighlightSpacing::Always`], so use [`HighlightSpacing::Always`] or
+    /// [`HighlightSpacing::Never`] to keep the header aligned with rows rendered with a selection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+    /// let rows = [Row::new(vec!["Cell1", "Cell2", "Cell3", "Cell4"])];
+    /// let table = Table::new(rows, [Constraint::Length(5); 4])
+    ///     .header(Row::new(vec!["Head1", "Head2", "Head3", "Head4"]));
+    /// let mut state = TableState::default();
+    /// // the header spans both panes, and each pane displays two columns
+    /// table
+    ///     .clone()
+    ///     .render_header_only(Rect::new(0, 0, 20, 1), &mut buf, 0);
+    /// table
+    ///     .clone()
+    ///     .render_rows_only(Rect::new(0, 1, 10, 9), &mut buf, &mut state, 0);
+    /// table.render_rows_only(Rect::new(10, 1, 10, 9), &mut buf, &mut state, 2);
+    /// ```
+    pub fn render_header_only(mut self, area: Rect, buf: &mut Buffer, column_offset: usize) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        self.skip_columns(column_offset);
+        buf.set_style(area, self.style);
+        let selection_width = self.selection_width(&TableState::default());
+        let columns_widths = self.get_columns_widths(area.width, selection_width);
+        self.render_header(area, buf, &columns_widths);
+    }
+
+    /// Renders only the rows of the table, starting from the column at `column_offset`
+    ///
+    /// The columns before `column_offset` are left out, and the following columns are laid out in
+    /// `area` as when rendering the whole table. The rows are rendered like with
+    /// [`StatefulWidget::render`], including the pinned rows, the selection and the scrolling, but
+    /// without the header, the [`Block`] and the caption.
+    ///
+    /// See [`Table::render_header_only`] for an example.
+    pub fn render_rows_only(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+        column_offset: usize,
+    ) {
+        self.skip_columns(column_offset);
+        self.header = None;
+        self.block = None;
+        self.caption = None;
+        StatefulWidget::render(self, area, buf, state);
+    }
+
+    /// Renders the table like [`StatefulWidget::render`] and returns where its cells were drawn
+    ///
+    /// The returned [`TableLayout`] contains the area of the screen and the plain text of every
+    /// visible cell of the rows, which is useful to let a screen reader describe the table. The
+    /// cells of the header and of the pinned rows are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+    /// let table = Table::new(
+    ///     [Row::new(vec!["Cell1", "Cell2"])],
+    ///     [Constraint::Length(5); 2],
+    /// );
+    /// let layout = table.render_with_layout(buf.area, &mut buf, &mut TableState::default());
+    /// assert_eq!(layout.cells[1].rect, Rect::new(6, 0, 5, 1));
+    /// assert_eq!(layout.cells[1].text, "Cell2");
+    /// ```
+    pub fn render_with_layout(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+    ) -> TableLayout {
+        let mut layout = TableLayout::default();
+        self.render_table(area, buf, state, Some(&mut layout));
+        layout
+    }
+}
+
+impl Widget for Table<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TableState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Table<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_table(area, buf, state, None);
+    }
+}
+
+// private methods for rendering
+impl<'a> Table<'a> {
+    /// Renders the table, recording the rendered cells in `layout` when given.
+    fn render_table(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+        layout: Option<&mut TableLayout>,
+    ) {
+        self.apply_filter();
+        if !self.incremental
+            || state.rendered_view != Some((area, state.selected, state.offset, state.line_offset))
+        {
+            state.mark_all_dirty();
+        }
+        if state.dirty_rows.is_none() {
+            buf.set_style(area, self.style);
+        }
+        state.visible_rows = (0, 0);
+        state.column_rects.clear();
+        state.header_area = Rect::default();
+        state.rows_area = Rect::default();
+        state.caption_area = Rect::default();
+
+        let previous_selection = (state.selected, state.offset);
+        if self.invalid_selection == InvalidSelection::Clear
+            && state
+                .selected
+                .is_some_and(|selected| selected >= self.rows.len())
+        {
+            state.selected = None;
+        }
+        if let Some(last_row) = self.rows.len().checked_sub(1) {
+            if self.invalid_selection == InvalidSelection::Clamp {
+                state.selected = state.selected.map(|selected| selected.min(last_row));
+            }
+            if state.auto_follow && state.selected.is_some() {
+                state.selected = Some(last_row);
+            }
+        }
+        state.selection_changed = state.selected != previous_selection.0;
+
+        let table_area = self.render_block(area, buf);
+        if table_area.is_empty() {
+            return;
+        }
+        if let Some(SharedFn(ref summary)) = self.collapsed_summary {
+            if table_area.height < 2 {
+                let summary = summary(&self.rows);
+                buf.set_line(table_area.x, table_area.y, &summary, table_area.width);
+                return;
+            }
+        }
+        let selection_width = self.selection_width(state);
+        self.fit_wrapped_header(table_area, selection_width, state);
+        let (header_area, mut pinned_area, mut rows_area, mut caption_area) =
+            self.layout(table_area);
+        let columns_widths = self.get_cached_columns_widths(
+            rows_area.width,
+            selection_width,
+            &mut state.columns_widths_cache,
+        );
+        let highlight_symbol = self.highlight_symbol.take().unwrap_or_default();
+        // the lines of the grid between the header, the pinned rows and the rows
+        let mut grid_lines = vec![];
+
+        if let Some(offset) = self.scrolled_header_offset(&self.rows, state, header_area, rows_area)
+        {
+            // the header scrolled away and leaves its space to the rows
+            state.offset = offset;
+            pinned_area.y = header_area.y;
+            rows_area = Rect {
+                y: pinned_area.bottom(),
+                height: rows_area.height + header_area.height,
+                ..rows_area
+            };
+        } else {
+            self.render_header(header_area, buf, &columns_widths);
+            if self.header.is_some() {
+                grid_lines.push(header_area.bottom().saturating_sub(1));
+                state.header_area = header_area;
+            }
+        }
+        let (above, below) = self.overflow_marker_lines(&self.rows, rows_area, state, state.offset);
+        if above {
+            let style = self.overflow_marker_style;
+            buf.set_stringn(
+                rows_area.x,
+                rows_area.y,
+                "▲ more",
+                rows_area.width as usize,
+                style,
+            );
+            rows_area.y += 1;
+            rows_area.height -= 1;
+        }
+        if below {
+            let (x, y) = (rows_area.x, rows_area.bottom() - 1);
+            let style = self.overflow_marker_style;
+            buf.set_stringn(x, y, "▼ more", rows_area.width as usize, style);
+            rows_area.height -= 1;
+        }
+        if state.offset != previous_selection.1 {
+            state.mark_all_dirty();
+        }
+        self.render_pinned_rows(pinned_area, buf, &columns_widths, &mut grid_lines);
+        state.rows_area = rows_area;
+        state.column_rects = columns_widths
+            .iter()
+            .map(|&(x, width)| Rect::new(rows_area.x + x, rows_area.y, width, rows_area.height))
+            .collect();
+        self.render_rows(
+            rows_area,
+            buf,
+            state,
+            selection_width,
+            &highlight_symbol,
+            &columns_widths,
+            &mut grid_lines,
+            layout,
+        );
+        let columns_area = Rect {
+            y: header_area.y,
+            height: rows_area.bottom() - header_area.y,
+            ..rows_area
+        };
+        self.render_column_range(state, columns_area, &columns_widths, buf);
+        let rows_height = self.rendered_rows_height(state).min(rows_area.height);
+        let mut empty_area = match self.anchor {
+            Anchor::Top => Rect {
+                y: rows_area.y + rows_height,
+                height: rows_area.height - rows_height,
+                ..rows_area
+            },
+            Anchor::Bottom => Rect {
+                height: rows_area.height - rows_height,
+                ..rows_area
+            },
+        };
+        if self.caption_position == CaptionPosition::BelowRows && self.anchor == Anchor::Top {
+            // the lines below the caption, down to its usual position, are left empty
+            let table_bottom = caption_area.bottom();
+            let rows_bottom = rows_area.y + rows_height;
+            caption_area.y = caption_area.y.min(rows_bottom + self.grid_line_width());
+            empty_area = Rect {
+                y: caption_area.bottom(),
+                height: table_bottom.saturating_sub(caption_area.bottom()),
+                ..rows_area
+            };
+        }
+        if let Some((fill_char, style)) = self.empty_fill {
+            for y in empty_area.top()..empty_area.bottom() {
+                for x in empty_area.left()..empty_area.right() {
+                    buf.get_mut(x, y).set_char(fill_char);
+                }
+            }
+            buf.set_style(empty_area, style);
+        }
+        if let Some(caption) = self.caption.take() {
+            caption.render(caption_area, buf);
+            state.caption_area = caption_area;
+        }
+        if let Some(border_type) = self.grid {
+            let grid_area = Rect {
+                height: caption_area.y - table_area.y,
+                ..table_area
+            };
+            let symbols = grid_symbols(border_type);
+            render_grid(
+                grid_area,
+                rows_area.x,
+                &columns_widths,
+                &grid_lines,
+                symbols,
+                buf,
+            );
+        }
+        state.selection_changed = (state.selected, state.offset) != previous_selection;
+        if self.incremental {
+            state.dirty_rows = Some(BTreeSet::new());
+            state.rendered_view = Some((area, state.selected, state.offset, state.line_offset));
+        }
+    }
+
+    /// Removes the first `count` columns from the widths, the column styles and the cells of the
+    /// header and the rows.
+    fn skip_columns(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        self.column_offset += count;
+        drain_front(&mut self.widths, count);
+        drain_front(&mut self.column_styles, count);
+        drain_front(&mut self.visible_columns, count);
+        for row in self
+            .rows
+            .iter_mut()
+            .chain(self.pinned_rows.iter_mut())
+            .chain(self.header.iter_mut())
+        {
+            drain_front(&mut row.cells, count);
+            if let Some(ref mut widths) = row.widths_override {
+                drain_front(widths, count);
+            }
+        }
+    }
+
+    /// Removes the rows not displayed by the [`Table::filter`], if any.
+    ///
+    /// The rows are removed in place, so the positions of the remaining rows are their positions
+    /// among the displayed rows.
+    fn apply_filter(&mut self) {
+        if let Some(SharedFn(filter)) = self.filter.take() {
+            let mut index = 0;
+            self.rows.retain(|row| {
+                index += 1;
+                filter(index - 1, row)
+            });
+        }
+    }
+
+    /// Returns the positions of the selectable rows among the rows displayed by the
+    /// [`Table::filter`].
+    fn selectable_rows(&self) -> Vec<usize> {
+        self.displayed_rows()
+            .enumerate()
+            .filter(|(_, row)| !row.unselectable)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the rows displayed by the [`Table::filter`], without removing the other rows.
+    fn displayed_rows(&self) -> impl Iterator<Item = &Row<'a>> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|&(index, row)| self.is_row_displayed(index, row))
+            .map(|(_, row)| row)
+    }
+
+    /// Returns whether the row at `index` is displayed by the [`Table::filter`].
+    fn is_row_displayed(&self, index: usize, row: &Row) -> bool {
+        self.filter
+            .as_ref()
+            .map_or(true, |SharedFn(filter)| filter(index, row))
+    }
+
+    /// Returns the bounds of the `rows` that the next render in `area` would draw, see
+    /// [`Table::visible_rows`].
+    ///
+    /// Like the other methods computing the bounds of the rows, this takes the rows considered,
+    /// which are the rows displayed by the [`Table::filter`], so that they can be computed without
+    /// removing the other rows from the table.
+    fn visible_row_bounds<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        area: Rect,
+        state: &TableState,
+    ) -> (usize, usize) {
+        let table_area = self.block.as_ref().map_or(area, |block| block.inner(area));
+        if table_area.is_empty() || rows.is_empty() {
+            return (0, 0);
+        }
+        let (header_area, _, rows_area, _) = self.layout(table_area);
+        let (offset, height) = self
+            .scrolled_header_offset(rows, state, header_area, rows_area)
+            .map_or((state.offset, rows_area.height), |offset| {
+                (offset, rows_area.height + header_area.height)
+            });
+        let (above, below) = self.overflow_marker_lines(
+            rows,
+            Rect {
+                height,
+                ..rows_area
+            },
+            state,
+            offset,
+        );
+        let height = height - u16::from(above) - u16::from(below);
+        self.row_bounds_with_line_offset(rows, state, offset, height)
+            .0
+    }
+
+    /// Returns the bounds of the rows drawn in a rows area of the given height, and the number of
+    /// lines of the first row hidden by the [`TableState::line_offset`].
+    ///
+    /// The first row is only clipped while it is the row at `offset`, so that the clipped lines
+    /// are given to the rows below it. When the rows are scrolled, e.g. to show the selected row,
+    /// no line is hidden.
+    fn row_bounds_with_line_offset<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        state: &TableState,
+        offset: usize,
+        height: u16,
+    ) -> ((usize, usize), u16) {
+        let padding = state.scroll_padding as usize;
+        let row_to_show = self.row_to_show(rows, state);
+        if let Some(centered_offset) = self.centered_offset(rows, state, height) {
+            return (
+                self.get_row_bounds(rows, row_to_show, centered_offset, height, padding),
+                0,
+            );
+        }
+        let Some(first_row) = rows.len().checked_sub(1).map(|last| offset.min(last)) else {
+            return ((0, 0), 0);
+        };
+        let line_offset = state.line_offset.min(
+            self.row_height_of_with_margin(first_row, rows[first_row].borrow())
+                .saturating_sub(1),
+        );
+        if line_offset > 0 {
+            let clipped_height = height.saturating_add(line_offset);
+            let bounds = self.get_row_bounds(rows, row_to_show, offset, clipped_height, padding);
+            if bounds.0 == first_row {
+                return (bounds, line_offset);
+            }
+        }
+        (
+            self.get_row_bounds(rows, row_to_show, offset, height, padding),
+            0,
+        )
+    }
+
+    /// Returns the offset at which the selected row is vertically centered in a rows area of the
+    /// given height, or `None` when the rows are not scrolled with
+    /// [`ScrollMode::CenterSelection`].
+    ///
+    /// The rows are not centered when they were scrolled away from the selected row with
+    /// [`TableState::scroll_to`].
+    fn centered_offset<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        state: &TableState,
+        height: u16,
+    ) -> Option<usize> {
+        if state.scroll_mode != ScrollMode::CenterSelection || state.scrolled_away {
+            return None;
+        }
+        let selected = state
+            .selected
+            .filter(|_| self.row_to_show(rows, state) == state.selected)?;
+        let selected = selected.min(rows.len().checked_sub(1)?);
+        let selected_height = self.row_height_of(selected, rows[selected].borrow());
+        let mut space_above = height.saturating_sub(selected_height) / 2;
+        let mut offset = selected;
+        while offset > 0 {
+            let row_height = self.row_height_of_with_margin(offset - 1, rows[offset - 1].borrow());
+            if row_height > space_above {
+                break;
+            }
+            space_above -= row_height;
+            offset -= 1;
+        }
+        Some(offset)
+    }
+
+    /// Returns whether the markers of the rows hidden above and below the rows drawn in `area`
+    /// are displayed, see [`Table::overflow_markers`].
+    fn overflow_marker_lines<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        area: Rect,
+        state: &TableState,
+        offset: usize,
+    ) -> (bool, bool) {
+        if !self.overflow_markers || rows.is_empty() || area.height < 3 {
+            return (false, false);
+        }
+        let mut markers = (false, false);
+        // each marker takes the line of a row, which may hide one more row
+        loop {
+            let height = area.height - u16::from(markers.0) - u16::from(markers.1);
+            let ((start, end), _) = self.row_bounds_with_line_offset(rows, state, offset, height);
+            let next = (markers.0 || start > 0, markers.1 || end < rows.len());
+            if next == markers {
+                return markers;
+            }
+            markers = next;
+        }
+    }
+
+    /// Returns the height taken by the rows drawn by the last call to [`Table::render_rows`],
+    /// without the line of the grid below the last row, which is drawn by the box of the grid.
+    fn rendered_rows_height(&self, state: &TableState) -> u16 {
+        if self.rows.is_empty() {
+            let count = self.skeleton_rows.map_or(0, |(count, _)| count);
+            let row_height = 1 + self.grid_line_width();
+            return u16::try_from(count)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(row_height)
+                .saturating_sub(self.grid_line_width());
+        }
+        let (start, end) = state.visible_rows;
+        let height = (start..end)
+            .map(|index| self.row_height_with_margin(index))
+            .fold(0, u16::saturating_add);
+        if end == self.rows.len() {
+            height.saturating_sub(self.grid_line_width())
+        } else {
+            height
+        }
+    }
+
+    /// Splits the table area into a header, pinned rows, rows and caption area
+    ///
+    /// When a grid is drawn, the header, pinned rows and rows areas are inside the box of the grid.
+    fn layout(&self, area: Rect) -> (Rect, Rect, Rect, Rect) {
+        let header_height = self
+            .header
+            .as_ref()
+            .map_or(0, |header| self.header_height(header));
+        let pinned_height = self
+            .pinned_rows
+            .iter()
+            .map(|row| self.fixed_row_height(row))
+            .fold(0, u16::saturating_add);
+        let caption_height = u16::from(self.caption.is_some());
+        let grid_line = self.grid_line_width();
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(grid_line),
+                Constraint::Length(header_height),
+                Constraint::Length(pinned_height),
+                Constraint::Min(0),
+                Constraint::Length(grid_line),
+                Constraint::Length(caption_height),
+            ])
+            .split(area);
+        let inside_grid = |area: Rect| Rect {
+            x: area.x + grid_line.min(area.width),
+            width: area.width.saturating_sub(grid_line.saturating_mul(2)),
+            ..area
+        };
+        (
+            inside_grid(layout[1]),
+            inside_grid(layout[2]),
+            inside_grid(layout[3]),
+            layout[5],
+        )
+    }
+
+    /// Returns the width of the lines of the grid, which is `1` when a grid is drawn.
+    fn grid_line_width(&self) -> u16 {
+        u16::from(self.grid.is_some())
+    }
+
+    /// Returns the height of the header or of a pinned row, including its margin and the line of
+    /// the grid below it.
+    fn fixed_row_height(&self, row: &Row) -> u16 {
+        row.height_with_margin()
+            .saturating_add(self.grid_line_width())
+    }
+
+    /// Returns the height of the header, including the [`Table::header_separator`].
+    fn header_height(&self, header: &Row) -> u16 {
+        self.fixed_row_height(header)
+            .saturating_add(u16::from(self.header_separator.is_some()))
+    }
+
+    /// Returns the offset of the rows when a non-sticky header is scrolled away, or `None` when the
+    /// header is displayed.
+    ///
+    /// The header is scrolled away when the rows need to be scrolled, either in the area left once
+    /// the header is hidden or in the area left below the header.
+    fn scrolled_header_offset<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        state: &TableState,
+        header_area: Rect,
+        rows_area: Rect,
+    ) -> Option<usize> {
+        if !self.scroll_header || self.header.is_none() {
+            return None;
+        }
+        let padding = state.scroll_padding as usize;
+        let full_height = header_area.height + rows_area.height;
+        let shown_row = self.row_to_show(rows, state);
+        let (start, _) = self.get_row_bounds(rows, shown_row, state.offset, full_height, padding);
+        if start > 0 {
+            return Some(start);
+        }
+        let (start, _) =
+            self.get_row_bounds(rows, shown_row, state.offset, rows_area.height, padding);
+        (start > 0).then_some(start)
+    }
+
+    /// Returns the row that must be visible: the last row when the state follows the last row,
+    /// otherwise the selected row unless the rows were scrolled away from it.
+    fn row_to_show<'r, R: Borrow<Row<'r>>>(&self, rows: &[R], state: &TableState) -> Option<usize> {
+        if state.auto_follow {
+            rows.len().checked_sub(1)
+        } else if state.scrolled_away {
+            // the rows scrolled with `TableState::scroll_to` stay at the offset
+            Some(state.offset)
+        } else if self.invalid_selection == InvalidSelection::KeepOffset
+            && state
+                .selected
+                .is_some_and(|selected| selected >= rows.len())
+        {
+            // an invalid selection does not scroll the rows
+            Some(state.offset)
+        } else {
+            state.selected
+        }
+    }
+
+    fn render_block(&mut self, area: Rect, buf: &mut Buffer) -> Rect {
+        if let Some(block) = self.block.take() {
+            let inner_area = block.inner(area);
+            block.render(area, buf);
+            inner_area
+        } else {
+            area
+        }
+    }
+
+    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+        if let Some(ref header) = self.header {
+            buf.set_style(area, self.cell_style);
+            buf.set_style(area, header.style);
+            for (column, left, right, cell) in header_cells(header, column_widths) {
+                let cell_area = Rect::new(area.x + left, area.y, right - left, area.height);
+                self.render_column_style(header, column, cell_area, buf);
+                if header.wrap {
+                    let cell = cell.wrapped(cell_area.width);
+                    let area = header.cell_area(&cell, cell_area);
+                    cell.render_with_matches(area, buf, None, self.east_asian_width);
+                } else {
+                    let area = header.cell_area(cell, cell_area);
+                    cell.render_with_matches(area, buf, None, self.east_asian_width);
+                }
+            }
+            if let Some(separator) = self.header_separator {
+                let y = area.y.saturating_add(header.content_height());
+                if y < area.bottom() {
+                    let rule = separator.to_string().repeat(area.width as usize);
+                    buf.set_stringn(area.x, y, rule, area.width as usize, header.style);
+                }
+            }
+        }
+    }
+
+    /// Sets the height of a wrapped [`Row::auto_height`] header to the number of lines of its
+    /// wrapped cells, as they are only known once the columns are laid out.
+    fn fit_wrapped_header(
+        &mut self,
+        table_area: Rect,
+        selection_width: u16,
+        state: &mut TableState,
+    ) {
+        if !self
+            .header
+            .as_ref()
+            .is_some_and(|header| header.wrap && header.auto_height)
+        {
+            return;
+        }
+        let width = table_area
+            .width
+            .saturating_sub(self.grid_line_width().saturating_mul(2));
+        let columns_widths =
+            self.get_cached_columns_widths(width, selection_width, &mut state.columns_widths_cache);
+        if let Some(header) = self.header.as_mut() {
+            let lines = header_cells(header, &columns_widths)
+                .into_iter()
+                .map(|(_, left, right, cell)| cell.wrapped(right - left).height())
+                .max()
+                .unwrap_or(0);
+            header.height = u16::try_from(lines).unwrap_or(u16::MAX).max(1);
+            header.auto_height = false;
+        }
+    }
+
+    fn render_pinned_rows(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        columns_widths: &[(u16, u16)],
+        grid_lines: &mut Vec<u16>,
+    ) {
+        let mut y_offset = 0;
+        for row in &self.pinned_rows {
+            if y_offset >= area.height {
+                break;
+            }
+            let row_height = self.fixed_row_height(row);
+            let row_area =
+                Rect::new(area.x, area.y + y_offset, area.width, row_height).intersection(area);
+            buf.set_style(row_area, self.cell_style);
+            buf.set_style(row_area, row.style);
+            self.render_cells(row, None, row_area, columns_widths, buf, None);
+            y_offset += row_height;
+            if self.grid.is_some() && row_height > 0 {
+                grid_lines.push(area.y + y_offset - 1);
+            }
+        }
+    }
+
+    /// Renders the cells of a row in the area of the row
+    ///
+    /// `row_index` is the index of the row in the rows of the table, or `None` for a pinned row.
+    fn render_cells(
+        &self,
+        row: &Row,
+        row_index: Option<usize>,
+        row_area: Rect,
+        columns_widths: &[(u16, u16)],
+        buf: &mut Buffer,
+        mut layout: Option<&mut TableLayout>,
+    ) {
+        let columns_widths = row.columns_widths(columns_widths);
+        let matches = self.match_query.as_deref().map(|query| MatchHighlight {
+            query,
+            style: self.match_style,
+            ignore_case: self.match_ignore_case,
+        });
+        for (column, ((x, width), cell)) in columns_widths.iter().zip(row.cells.iter()).enumerate()
+        {
+            let cell_area = Rect::new(row_area.x + x, row_area.y, *width, row_area.height)
+                .intersection(row_area);
+            match row_index {
+                Some(row_index) => {
+                    buf.set_style(cell_area, self.base_cell_style(row_index, column));
+                }
+                None => self.render_column_style(row, column, cell_area, buf),
+            }
+            cell.render_with_matches(
+                row.cell_area(cell, cell_area),
+                buf,
+                matches.as_ref(),
+                self.east_asian_width,
+            );
+            if let (Some(layout), Some(row)) = (layout.as_deref_mut(), row_index) {
+                layout.cells.push(CellLayout {
+                    row,
+                    col: column,
+                    rect: cell_area,
+                    text: cell.plain_text(),
+                });
+            }
+        }
+    }
+
+    /// Returns the [`Table::alternating_column_styles`] style of the column at `column` among the
+    /// columns left by [`Table::skip_columns`].
+    fn column_band_style(&self, column: usize) -> Style {
+        self.alternating_column_styles
+            .map_or_else(Style::default, |(even, odd)| {
+                if (self.column_offset + column) % 2 == 0 {
+                    even
+                } else {
+                    odd
+                }
+            })
+    }
+
+    /// Returns the style of a cell of the rows before the hover and highlight styles are applied.
+    ///
+    /// See [`Table::resolve_cell_style`] for the order in which the styles are patched.
+    fn base_cell_style(&self, row_index: usize, column: usize) -> Style {
+        let base_style = self
+            .style
+            .patch(self.cell_style)
+            .patch(self.column_band_style(column));
+        let Some(row) = self.rows.get(row_index) else {
+            return base_style;
+        };
+        let mut style = base_style.patch(row.style);
+        if let Some(SharedFn(ref decorator)) = self.row_decorator {
+            style = style.patch(decorator(row_index, row));
+        }
+        if let Some(&column_style) = self.column_styles.get(column) {
+            style = style.patch(column_style);
+        }
+        if let Some(cell) = row.cells.get(column) {
+            style = style.patch(Styled::style(cell));
+        }
+        style
+    }
+
+    /// Renders the placeholder rows set by [`Table::skeleton_rows`], one bar per column.
+    fn render_skeleton_rows(&self, area: Rect, buf: &mut Buffer, columns_widths: &[(u16, u16)]) {
+        let Some((count, style)) = self.skeleton_rows else {
+            return;
+        };
+        let row_height = 1 + self.grid_line_width();
+        for y in (area.top()..area.bottom())
+            .step_by(row_height.into())
+            .take(count)
+        {
+            for &(x, width) in columns_widths {
+                let bar_area = Rect::new(area.x + x, y, width, 1).intersection(area);
+                for x in bar_area.left()..bar_area.right() {
+                    buf.get_mut(x, y).set_symbol(symbols::bar::HALF);
+                }
+                buf.set_style(bar_area, style);
+            }
+        }
+    }
+
+    /// Renders the [`Table::column_range_style`] over the columns of the range highlighted by the
+    /// state, from the top of `area` to its bottom.
+    fn render_column_range(
+        &self,
+        state: &TableState,
+        area: Rect,
+        columns_widths: &[(u16, u16)],
+        buf: &mut Buffer,
+    ) {
+        for (column, &(x, width)) in columns_widths.iter().enumerate() {
+            if state.is_column_in_range(column + self.column_offset) {
+                let column_area = Rect::new(area.x + x, area.y, width, area.height);
+                buf.set_style(column_area.intersection(area), self.column_range_style);
+            }
+        }
+    }
+
+    /// Applies the style of the given column, if any, to the area of one of its cells
+    ///
+    /// This is used for the cells of the header and of the pinned rows: the band of the column is
+    /// rendered first, and the row style is patched over it.
+    fn render_column_style(&self, row: &Row, column: usize, area: Rect, buf: &mut Buffer) {
+        if self.alternating_column_styles.is_some() {
+            buf.set_style(area, self.column_band_style(column).patch(row.style));
+        }
+        if let Some(&style) = self.column_styles.get(column) {
+            buf.set_style(area, style);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_rows(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+        selection_width: u16,
+        highlight_symbol: &str,
+        columns_widths: &[(u16, u16)],
+        grid_lines: &mut Vec<u16>,
+        mut layout: Option<&mut TableLayout>,
+    ) {
+        if self.rows.is_empty() {
+            self.render_skeleton_rows(area, buf, columns_widths);
+            return;
+        }
+
+        let ((start_index, end_index), line_offset) =
+            self.row_bounds_with_line_offset(&self.rows, state, state.offset, area.height);
+        if state.offset != start_index || state.line_offset != line_offset {
+            state.mark_all_dirty();
+        }
+        if self.incremental && state.dirty_rows.is_none() {
+            // nothing drawn by the previous render is kept when all the rows are drawn
+            clear_area(area, buf);
+            buf.set_style(area, self.style);
+        }
+        state.offset = start_index;
+        state.line_offset = line_offset;
+        state.visible_rows = (start_index, end_index);
+
+        if line_offset == 0 {
+            self.draw_rows(
+                area,
+                buf,
+                state,
+                selection_width,
+                highlight_symbol,
+                columns_widths,
+                grid_lines,
+                layout,
+            );
+            return;
+        }
+        // the rows are drawn in a buffer starting `line_offset` lines above the area, whose lines
+        // below these lines are then copied to the area
+        let scrolled_area = Rect {
+            height: area.height.saturating_add(line_offset),
+            ..area
+        };
+        let mut scrolled_buf = Buffer::empty(scrolled_area);
+        scrolled_buf.blit_from(buf, area, (area.x, area.y + line_offset));
+        let first_grid_line = grid_lines.len();
+        let first_cell = layout.as_ref().map_or(0, |layout| layout.cells.len());
+        self.draw_rows(
+            scrolled_area,
+            &mut scrolled_buf,
+            state,
+            selection_width,
+            highlight_symbol,
+            columns_widths,
+            grid_lines,
+            layout.as_deref_mut(),
+        );
+        let visible_area = Rect {
+            y: area.y + line_offset,
+            ..area
+        };
+        buf.blit_from(&scrolled_buf, visible_area, (area.x, area.y));
+        let scrolled_grid_lines = grid_lines.split_off(first_grid_line);
+        grid_lines.extend(
+            scrolled_grid_lines
+                .into_iter()
+                .filter(|&y| y >= visible_area.y)
+                .map(|y| y - line_offset),
+        );
+        if let Some(layout) = layout {
+            let scrolled_cells = layout.cells.split_off(first_cell);
+            layout
+                .cells
+                .extend(scrolled_cells.into_iter().filter_map(|mut cell| {
+                    let rect = cell.rect.intersection(visible_area);
+                    if rect.is_empty() {
+                        return None;
+                    }
+                    cell.rect = Rect {
+                        y: rect.y - line_offset,
+                        ..rect
+                    };
+                    Some(cell)
+                }));
+        }
+    }
+
+    /// Draws the rows between the bounds in [`TableState::visible_rows`] in `area`.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_rows(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &TableState,
+        selection_width: u16,
+        highlight_symbol: &str,
+        columns_widths: &[(u16, u16)],
+        grid_lines: &mut Vec<u16>,
+        mut layout: Option<&mut TableLayout>,
+    ) {
+        let (start_index, end_index) = state.visible_rows;
+        let mut y_offset = match self.anchor {
+            Anchor::Top => 0,
+            Anchor::Bottom => {
+                let rows_height = (start_index..end_index)
+                    .map(|index| self.row_height_with_margin(index))
+                    .fold(0, u16::saturating_add);
+                area.height.saturating_sub(rows_height)
+            }
+        };
+        for (i, row) in self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(end_index - start_index)
+        {
+            let row_area = Rect::new(
+                area.x,
+                area.y + y_offset,
+                area.width,
+                self.row_height_with_margin(i),
+            );
+            y_offset += self.row_height_with_margin(i);
+            // the grid is closed by its box below the last row
+            if self.grid.is_some() && row_area.height > 0 && i + 1 < self.rows.len() {
+                grid_lines.push(row_area.bottom() - 1);
+            }
+            if !state.is_row_dirty(i) {
+                continue;
+            }
+            if self.incremental {
+                // clear what the previous render drew in the area of the row
+                clear_area(row_area.intersection(area), buf);
+                buf.set_style(row_area, self.style);
+            }
+            buf.set_style(row_area, self.cell_style);
+            buf.set_style(row_area, row.style);
+            if let Some(SharedFn(ref decorator)) = self.row_decorator {
+                buf.set_style(row_area, decorator(i, row));
+            }
+
+            // the cells keep the normal height of the row, the detail fills the expanded lines
+            let cells_height = row.content_height().min(self.row_height(i));
+            let cells_area = Rect {
+                height: cells_height.min(row_area.height),
+                ..row_area
+            };
+            let is_selected = state.selected().is_some_and(|index| index == i);
+            let selection_area = self.selection_area(row_area, selection_width);
+            if selection_width > 0 && is_selected {
+                // the symbol is clamped to the selection column, which is itself clamped to the
+                // area, as "get_columns_widths" does not bind it to max table.width(). It is only
+                // repeated on the lines of the cells, not on the margin or the detail of the row,
+                // and not drawn at all for a row of height 0
+                let cells_lines = cells_area.intersection(area).height;
+                let symbol_lines = if self.highlight_symbol_repeat {
+                    cells_lines
+                } else {
+                    cells_lines.min(1)
+                };
+                for y in row_area.y..row_area.y + symbol_lines {
+                    buf.set_stringn(
+                        selection_area.x,
+                        y,
+                        highlight_symbol,
+                        selection_area.width as usize,
+                        row.style,
+                    );
+                }
+            };
+            match row.widths_override {
+                Some(ref widths) => {
+                    let row_columns_widths =
+                        self.layout_columns(widths, area.width, selection_width);
+                    self.render_cells(
+                        row,
+                        Some(i),
+                        cells_area,
+                        &row_columns_widths,
+                        buf,
+                        layout.as_deref_mut(),
+                    );
+                }
+                None => self.render_cells(
+                    row,
+                    Some(i),
+                    cells_area,
+                    columns_widths,
+                    buf,
+                    layout.as_deref_mut(),
+                ),
+            }
+            if let Some(ref detail) = row.detail {
+                let detail_x = match self.direction {
+                    HorizontalDirection::LeftToRight => selection_area.right(),
+                    HorizontalDirection::RightToLeft => row_area.x,
+                };
+                let detail_area = Rect {
+                    x: detail_x,
+                    y: cells_area.bottom(),
+                    width: row_area.width - selection_area.width,
+                    height: self.row_height(i) - cells_height,
+                }
+                .intersection(area);
+                render_text(detail, detail_area, buf);
+            }
+            if state.hovered == Some(i) {
+                buf.set_style(row_area, self.hover_style);
+            }
+            if state.is_in_selection(i) {
+                buf.set_style(row_area, self.highlight_style);
+            }
+            if is_selected {
+                if self.highlight_symbol_use_highlight_style {
+                    // the styles of the row are removed from the selection column
+                    let style = Style::reset()
+                        .patch(self.style)
+                        .patch(self.cell_style)
+                        .patch(self.highlight_style);
+                    buf.set_style(selection_area, style);
+                }
+                buf.set_style(selection_area, self.highlight_symbol_style);
+            }
+        }
+    }
+
+    /// Returns the constraints of the columns, which are equal widths when `.widths()` has not been
+    /// called.
+    fn column_constraints(&self, max_width: u16) -> Vec<Constraint> {
+        if self.widths.is_empty() {
+            let col_count = self
+                .rows
+                .iter()
+                .chain(self.header.iter())
+                .map(|r| r.cells.len())
+                .max()
+                .unwrap_or(0);
+            let visible_count = (0..col_count)
+                .filter(|&column| self.is_column_visible(column))
+                .count();
+            // There are `visible_count - 1` spaces between the displayed columns
+            let spacers = u16::try_from(visible_count.saturating_sub(1)).unwrap_or(u16::MAX);
+            let spacing = self.effective_column_spacing(max_width);
+            let total_space = max_width
+                .saturating_sub(spacing.saturating_mul(spacers))
+                .saturating_sub(self.edge_padding.saturating_mul(2));
+            // Divide the remaining space between each displayed column equally
+            let columns = u16::try_from(visible_count).unwrap_or(u16::MAX).max(1);
+            let width = total_space / columns;
+            if !self.distribute_remainder {
+                return vec![Constraint::Length(width); col_count];
+            }
+            // the first displayed columns are one cell wider, to fill the remaining space
+            let mut remainder = total_space % columns;
+            (0..col_count)
+                .map(|column| {
+                    if remainder > 0 && self.is_column_visible(column) {
+                        remainder -= 1;
+                        Constraint::Length(width + 1)
+                    } else {
+                        Constraint::Length(width)
+                    }
+                })
+                .collect()
+        } else {
+            self.widths.to_vec()
+        }
+    }
+
+    /// Returns the spacing between the columns of a table of the given width, which is `0` when
+    /// the table is narrower than `min_width_for_spacing`.
+    fn effective_column_spacing(&self, max_width: u16) -> u16 {
+        if self.grid.is_some() {
+            // the vertical lines of the grid are drawn in the spacing
+            1
+        } else if max_width < self.min_width_for_spacing {
+            0
+        } else {
+            self.column_spacing
+        }
+    }
+
+    /// Get all offsets and widths of all user specified columns.
+    ///
+    /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
+    /// and a default of equal widths is returned.
+    fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
+        let widths = self.column_constraints(max_width);
+        self.layout_columns(&widths, max_width, selection_width)
+    }
+
+    /// Get all offsets and widths of all user specified columns, reusing the widths of the
+    /// previous render when they are computed from the same inputs.
+    ///
+    /// Returns (x, width).
+    fn get_cached_columns_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        cache: &mut ColumnsWidthsCache,
+    ) -> Vec<(u16, u16)> {
+        if self.width_resolver.is_some() {
+            // the widths computed by a resolver can't be known to depend only on the key
+            return self.get_columns_widths(max_width, selection_width);
+        }
+        let key = ColumnsWidthsKey {
+            constraints: self.column_constraints(max_width),
+            max_width,
+            selection_width,
+            column_spacing: self.effective_column_spacing(max_width),
+            edge_padding: self.edge_padding,
+            min_column_width: self.min_column_width,
+            segment_size: self.segment_size,
+            visible_columns: self.visible_columns.clone(),
+            direction: self.direction,
+        };
+        cache.get_or_compute(key, |widths| {
+            self.layout_columns(widths, max_width, selection_width)
+        })
+    }
+
+    /// Get the offsets and widths of the columns laid out with the given constraints.
+    ///
+    /// Returns (x, width).
+    fn layout_columns(
+        &self,
+        widths: &[Constraint],
+        max_width: u16,
+        selection_width: u16,
+    ) -> Vec<(u16, u16)> {
+        // the trailing padding is left out of the layout so that the last column can still take
+        // the remaining space
+        let inner_width = max_width.saturating_sub(self.edge_padding);
+        let columns = widths.len();
+        let visible_widths = widths
+            .iter()
+            .enumerate()
+            .filter(|&(column, _)| self.is_column_visible(column))
+            .map(|(_, &width)| width);
+        let spacing = self.effective_column_spacing(max_width);
+        let widths = if let Some(SharedFn(ref resolver)) = self.width_resolver {
+            let start = selection_width.saturating_add(self.edge_padding);
+            let available = inner_width.saturating_sub(start);
+            resolver(available, &visible_widths.collect_vec(), spacing)
+                .into_iter()
+                .map(|(x, width)| (x.saturating_add(start), width))
+                .collect_vec()
+        } else {
+            let constraints = [selection_width, self.edge_padding]
+                .into_iter()
+                .map(Constraint::Length)
+                .chain(Itertools::intersperse(
+                    visible_widths,
+                    Constraint::Length(spacing),
+                ))
+                .collect_vec();
+            let layout = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .segment_size(self.segment_size)
+                .split(Rect::new(0, 0, inner_width, 1));
+            layout
+                .iter()
+                .skip(2) // skip selection column and leading padding
+                .step_by(2) // skip spacing between columns
+                .map(|c| (c.x, c.width))
+                .collect_vec()
+        };
+        let widths = self.apply_min_column_width(widths.into_iter(), inner_width);
+        let widths = collapse_spacing_after_empty_columns(widths);
+        let widths = self.insert_hidden_columns(widths, columns);
+        match self.direction {
+            HorizontalDirection::LeftToRight => widths,
+            HorizontalDirection::RightToLeft => widths
+                .into_iter()
+                .map(|(x, width)| (max_width.saturating_sub(x + width), width))
+                .collect(),
+        }
+    }
+
+    /// Returns the area of the selection column within the area of a row.
+    fn selection_area(&self, row_area: Rect, selection_width: u16) -> Rect {
+        let width = selection_width.min(row_area.width);
+        match self.direction {
+            HorizontalDirection::LeftToRight => Rect { width, ..row_area },
+            HorizontalDirection::RightToLeft => Rect {
+                x: row_area.right() - width,
+                width,
+                ..row_area
+            },
+        }
+    }
+
+    /// Returns whether the column at the given position is displayed, see
+    /// [`Table::visible_columns`].
+    fn is_column_visible(&self, column: usize) -> bool {
+        self.visible_columns.get(column).copied().unwrap_or(true)
+    }
+
+    /// Inserts the hidden columns among the `(x, width)` of the visible columns, with a width of
+    /// `0` at the position of the next visible column, so that the result is indexed by the
+    /// positions of the `columns` columns.
+    fn insert_hidden_columns(&self, widths: Vec<(u16, u16)>, columns: usize) -> Vec<(u16, u16)> {
+        if self.visible_columns.iter().all(|&visible| visible) {
+            return widths;
+        }
+        let mut result = Vec::with_capacity(columns);
+        let mut widths = widths.into_iter().peekable();
+        for column in 0..columns {
+            if self.is_column_visible(column) {
+                // the columns that do not fit are dropped
+                let Some(width) = widths.next() else {
+                    break;
+                };
+                result.push(width);
+            } else {
+                let x = widths.peek().map_or_else(
+                    || result.last().map_or(0, |&(x, width)| x + width),
+                    |&(x, _)| x,
+                );
+                result.push((x, 0));
+            }
+        }
+        result
+    }
+
+    /// Widens the columns narrower than `min_column_width`, shifting the following columns to the
+    /// right, and drops the columns that no longer fit in `max_width`.
+    fn apply_min_column_width(
+        &self,
+        widths: impl Iterator<Item = (u16, u16)>,
+        max_width: u16,
+    ) -> Vec<(u16, u16)> {
+        let mut shift = 0u16;
+        widths
+            .map_while(|(x, width)| {
+                let x = x.saturating_add(shift);
+                let floored = width.max(self.min_column_width);
+                shift = shift.saturating_add(floored - width);
+                (x.saturating_add(floored) <= max_width).then_some((x, floored))
+            })
+            .collect()
+    }
+
+    fn get_row_bounds<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        selected: Option<usize>,
+        offset: usize,
+        max_height: u16,
+        scroll_padding: usize,
+    ) -> (usize, usize) {
+        if rows.is_empty() {
+            return (0, 0);
+        }
+        let offset = offset.min(rows.len().saturating_sub(1));
+        let limit = self.visible_rows_limit();
+        // the heights are added up in a wider type, as the rows considered can be taller than
+        // the maximum height altogether
+        let max_height_wide = u32::from(max_height);
+        let row_height =
+            |index: usize| u32::from(self.row_height_of_with_margin(index, rows[index].borrow()));
+        let mut start = offset;
+        let mut end = offset;
+        let mut height = 0u32;
+        for index in (offset..rows.len()).take(limit) {
+            let content_height = self.row_height_of(index, rows[index].borrow());
+            if height.saturating_add(u32::from(content_height)) > max_height_wide {
+                break;
+            }
+            height = height.saturating_add(row_height(index));
+            end += 1;
+        }
+
+        let (first, last) = match selected {
+            Some(selected) => {
+                let selected = selected.min(rows.len().saturating_sub(1));
+                self.padded_selection(rows, selected, scroll_padding, max_height)
+            }
+            None => (0, 0),
+        };
+        // The inner loops stop before removing the last remaining row so that the bounds never
+        // cross, even when a row is taller than the available height. Zero height rows do not
+        // reduce the height when they are removed, so they must not be relied on to terminate.
+        while last >= end {
+            height = height.saturating_add(row_height(end));
+            end = end.saturating_add(1);
+            while (height > max_height_wide || end.saturating_sub(start) > limit)
+                && start.saturating_add(1) < end
+            {
+                height = height.saturating_sub(row_height(start));
+                start = start.saturating_add(1);
+            }
+        }
+        while first < start {
+            start = start.saturating_sub(1);
+            height = height.saturating_add(row_height(start));
+            while (height > max_height_wide || end.saturating_sub(start) > limit)
+                && start.saturating_add(1) < end
+            {
+                end = end.saturating_sub(1);
+                height = height.saturating_sub(row_height(end));
+            }
+        }
+        // When the last row is visible, the rows above it fill the remaining height so that a
+        // stale offset (e.g. after rows were removed) does not leave blank rows at the bottom
+        if end == rows.len() {
+            while start > 0
+                && end.saturating_sub(start) < limit
+                && height.saturating_add(row_height(start - 1)) <= max_height_wide
+            {
+                start = start.saturating_sub(1);
+                height = height.saturating_add(row_height(start));
+            }
+        }
+        (start, end)
+    }
+
+    /// Returns the first and last index of the rows that must be visible for the selected row to
+    /// keep `scroll_padding` rows of context above and below it.
+    ///
+    /// The padding is reduced until the rows fit within `max_height`, and is naturally clamped at
+    /// the start and end of the rows.
+    fn padded_selection<'r, R: Borrow<Row<'r>>>(
+        &self,
+        rows: &[R],
+        selected: usize,
+        scroll_padding: usize,
+        max_height: u16,
+    ) -> (usize, usize) {
+        // the padding is clamped at the start and end of the rows past their count
+        let mut padding = scroll_padding.min(rows.len());
+        loop {
+            let first = selected.saturating_sub(padding);
+            let last = selected
+                .saturating_add(padding)
+                .min(rows.len().saturating_sub(1));
+            let height = (first..=last)
+                .map(|index| u32::from(self.row_height_of_with_margin(index, rows[index].borrow())))
+                .fold(0, u32::saturating_add);
+            if padding == 0
+                || (height <= u32::from(max_height) && last - first < self.visible_rows_limit())
+            {
+                return (first, last);
+            }
+            padding -= 1;
+        }
+    }
+
+    /// Returns the maximum number of rows displayed at once.
+    fn visible_rows_limit(&self) -> usize {
+        self.max_visible_rows.map_or(usize::MAX, usize::from)
+    }
+
+    /// Returns the height of the row at `index` without its margin, which is its expanded height
+    /// when it is expanded.
+    fn row_height(&self, index: usize) -> u16 {
+        self.row_height_of(index, &self.rows[index])
+    }
+
+    /// Returns the height of `row` displayed at `index`, see [`Table::row_height`].
+    fn row_height_of(&self, index: usize, row: &Row) -> u16 {
+        self.expanded_row_height
+            .as_ref()
+            .and_then(|SharedFn(expanded_row_height)| expanded_row_height(index))
+            .unwrap_or_else(|| row.content_height())
+    }
+
+    /// Returns the height of the row at `index` including its margin and the line of the grid
+    /// below it.
+    fn row_height_with_margin(&self, index: usize) -> u16 {
+        self.row_height_of_with_margin(index, &self.rows[index])
+    }
+
+    /// Returns the height of `row` displayed at `index` including its margin and the line of the
+    /// grid below it.
+    fn row_height_of_with_margin(&self, index: usize, row: &Row) -> u16 {
+        self.row_height_of(index, row)
+            .saturating_add(row.vertical_margin())
+            .saturating_add(self.grid_line_width())
+    }
+
+    /// Returns the width of the selection column if a row is selected, or the highlight_spacing is
+    /// set to show the column always, otherwise 0.
+    ///
+    /// The width is taken from `selection_width_override` when set, otherwise it is the width of
+    /// the highlight symbol.
+    fn selection_width(&self, state: &TableState) -> u16 {
+        if self.reserves_selection_column(state) {
+            self.selection_width_override.unwrap_or_else(|| {
+                self.highlight_symbol
+                    .as_ref()
+                    .map_or(0, |s| self.str_width(s)) as u16
+            })
+        } else {
+            0
+        }
+    }
+
+    /// Returns the width of the string, measuring ambiguous width characters as double width when
+    /// `east_asian_width` is enabled.
+    fn str_width(&self, s: &str) -> usize {
+        cell::str_width(s, self.east_asian_width)
+    }
+}
+
+/// A function computing an additional style for a row, see [`Table::row_decorator`]
+type RowDecoratorFn<'a> = dyn Fn(usize, &Row) -> Style + Send + Sync + 'a;
+
+/// A function deciding whether a row is displayed, see [`Table::filter`]
+type RowFilterFn<'a> = dyn Fn(usize, &Row) -> bool + Send + Sync + 'a;
+
+/// A function computing the summary of the rows of a tiny table, see
+/// [`Table::collapse_when_tiny`]
+type CollapsedSummaryFn<'a> = dyn Fn(&[Row<'a>]) -> Line<'a> + Send + Sync + 'a;
+
+/// A function laying out the columns, see [`Table::width_resolver`]
+type WidthResolverFn<'a> = dyn Fn(u16, &[Constraint], u16) -> Vec<(u16, u16)> + Send + Sync + 'a;
+
+/// A function computing the expanded height of a row, see [`Table::expanded_row_height`]
+type ExpandedRowHeightFn<'a> = dyn Fn(usize) -> Option<u16> + Send + Sync + 'a;
+
+/// Removes the spacing that follows the columns of width 0, so that collapsed columns do not
+/// waste any cell.
+///
+/// The following columns are shifted to the left, starting at the same position as the collapsed
+/// column, so the positions are still in increasing order.
+fn collapse_spacing_after_empty_columns(widths: Vec<(u16, u16)>) -> Vec<(u16, u16)> {
+    let mut shift = 0;
+    let mut collapsed_x = None;
+    widths
+        .into_iter()
+        .map(|(x, width)| {
+            if let Some(collapsed_x) = collapsed_x.take() {
+                shift += x - collapsed_x;
+            }
+            if width == 0 {
+                collapsed_x = Some(x);
+            }
+            (x - shift, width)
+        })
+        .collect()
+}
+
+/// Returns the column, the left and the right edges of each cell of the `header`, a spanning cell
+/// extending to the far edge of the last column it covers.
+fn header_cells<'r, 'a>(
+    header: &'r Row<'a>,
+    columns_widths: &[(u16, u16)],
+) -> Vec<(usize, u16, u16, &'r Cell<'a>)> {
+    let columns_widths = header.columns_widths(columns_widths);
+    let mut columns = columns_widths.iter().enumerate();
+    let mut cells = vec![];
+    for cell in &header.cells {
+        let Some((column, &(x, width))) = columns.next() else {
+            break;
+        };
+        let (left, right) = columns
+            .by_ref()
+            .take(cell.column_span() - 1)
+            .fold((x, x + width), |(left, right), (_, &(x, width))| {
+                (left.min(x), right.max(x + width))
+            });
+        cells.push((column, left, right, cell));
+    }
+    cells
+}
+
+/// Removes the first `count` items of `items`, or all of them when there are fewer.
+fn drain_front<T>(items: &mut Vec<T>, count: usize) {
+    items.drain(..count.min(items.len()));
+}
+
+/// Returns the text of a cell as a field of [`Table::to_delimited`], on a single line and quoted
+/// when it contains the separator or a quote.
+fn delimited_field(text: &str, sep: char) -> String {
+    let text = text.replace('\n', " ");
+    if text.contains(sep) || text.contains('"') {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+/// Renders the lines of `text` from the top left corner of the area, truncating what does not fit.
+fn render_text(text: &Text, area: Rect, buf: &mut Buffer) {
+    for (line, y) in text.lines.iter().zip(area.top()..area.bottom()) {
+        buf.set_line(area.x, y, line, area.width);
+    }
+}
+
+/// Returns the line symbols used to draw a grid of the given border type.
+const fn grid_symbols(border_type: BorderType) -> line::Set {
+    match border_type {
+        BorderType::Plain | BorderType::QuadrantInside | BorderType::QuadrantOutside => {
+            line::NORMAL
+        }
+        BorderType::Rounded => line::ROUNDED,
+        BorderType::Double => line::DOUBLE,
+        BorderType::Thick => line::THICK,
+    }
+}
+
+/// Position of a line of a grid relative to the box of the grid
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GridLine {
+    Start,
+    Middle,
+    End,
+}
+
+/// Draws the box of a grid on the edges of `area`, with the vertical lines in the spacing after
+/// the columns and the horizontal lines at the `grid_lines` positions.
+///
+/// `columns_x` is the position from which the columns are offset.
+fn render_grid(
+    area: Rect,
+    columns_x: u16,
+    columns_widths: &[(u16, u16)],
+    grid_lines: &[u16],
+    symbols: line::Set,
+    buf: &mut Buffer,
+) {
+    if area.width < 2 || area.height < 2 {
+        return;
+    }
+    // the spacing after a column is collapsed when the column is empty
+    let columns_lines = columns_widths
+        .iter()
+        .sorted_by_key(|&&(x, width)| (x, width))
+        .tuple_windows()
+        .filter(|((x, width), (next_x, _))| x + width < *next_x)
+        .map(|((x, width), _)| columns_x + x + width)
+        .collect_vec();
+    let line = |position: u16, start: u16, end: u16, lines: &[u16]| {
+        if position == start {
+            Some(GridLine::Start)
+        } else if position == end - 1 {
+            Some(GridLine::End)
+        } else {
+            lines.contains(&position).then_some(GridLine::Middle)
+        }
+    };
+    for y in area.top()..area.bottom() {
+        let horizontal = line(y, area.top(), area.bottom(), grid_lines);
+        for x in area.left()..area.right() {
+            let vertical = line(x, area.left(), area.right(), &columns_lines);
+            let symbol = match (horizontal, vertical) {
+                (None, None) => continue,
+                (None, Some(_)) => symbols.vertical,
+                (Some(_), None) => symbols.horizontal,
+                (Some(GridLine::Start), Some(GridLine::Start)) => symbols.top_left,
+                (Some(GridLine::Start), Some(GridLine::Middle)) => symbols.horizontal_down,
+                (Some(GridLine::Start), Some(GridLine::End)) => symbols.top_right,
+                (Some(GridLine::Middle), Some(GridLine::Start)) => symbols.vertical_right,
+                (Some(GridLine::Middle), Some(GridLine::Middle)) => symbols.cross,
+                (Some(GridLine::Middle), Some(GridLine::End)) => symbols.vertical_left,
+                (Some(GridLine::End), Some(GridLine::Start)) => symbols.bottom_left,
+                (Some(GridLine::End), Some(GridLine::Middle)) => symbols.horizontal_up,
+                (Some(GridLine::End), Some(GridLine::End)) => symbols.bottom_right,
+            };
+            buf.get_mut(x, y).set_symbol(symbol);
+        }
+    }
+}
+
+/// Resets the cells of the area to their default content and style.
+fn clear_area(area: Rect, buf: &mut Buffer) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buf.get_mut(x, y).reset();
+        }
+    }
+}
+
+fn ensure_percentages_less_than_100(widths: &[Constraint]) {
+    widths.iter().for_each(|&w| {
+        if let Constraint::Percentage(p) = w {
+            assert!(
+                p <= 100,
+                "Percentages should be between 0 and 100 inclusively."
+            )
+        }
+    });
+}
+
+impl<'a> Styled for Table<'a> {
+    type Item = Table<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        vec,
+    };
+
+    use super::*;
+    use crate::{
+        layout::Constraint::*,
+        style::{Color, Modifier, Style, Stylize},
+        text::Line,
+        widgets::Borders,
+    };
+
+    #[test]
+    fn new() {
+        let rows = [Row::new(vec![Cell::from("")])];
+        let widths = [Constraint::Percentage(100)];
+        let table = Table::new(rows.clone(), widths);
+        assert_eq!(table.rows, rows);
+        assert_eq!(table.widths, widths);
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Table>();
+        assert_send_sync::<Row>();
+        assert_send_sync::<Cell>();
+        assert_send_sync::<TableState>();
+    }
+
+    #[test]
+    fn widths() {
+        let table = Table::default().widths([Constraint::Length(100)]);
+        assert_eq!(table.widths, [Constraint::Length(100)]);
+
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let table = Table::default().widths(&[Constraint::Length(100)]);
+        assert_eq!(table.widths, [Constraint::Length(100)]);
+
+        let table = Table::default().widths(vec![Constraint::Length(100)]);
+        assert_eq!(table.widths, [Constraint::Length(100)]);
+
+        let table = Table::default().widths(&vec![Constraint::Length(100)]);
+        assert_eq!(table.widths, [Constraint::Length(100)]);
+
+        let table = Table::default().widths([100].into_iter().map(Constraint::Length));
+        assert_eq!(table.widths, [Constraint::Length(100)]);
+    }
+
+    #[test]
+    fn from_rows() {
+        let table = Table::from_rows([["a", "b"], ["c", "d"]], [Length(1), Length(2)]);
+        assert_eq!(
+            table.rows,
+            [Row::new(vec!["a", "b"]), Row::new(vec!["c", "d"])]
+        );
+        assert_eq!(table.widths, [Length(1), Length(2)]);
+    }
+
+    #[test]
+    fn rows() {
+        let rows = [Row::new(vec![Cell::from("")])];
+        let table = Table::default().rows(rows.clone());
+        assert_eq!(table.rows, rows);
+    }
+
+    #[test]
+    fn push_row() {
+        let mut table = Table::default();
+        table.push_row(Row::new(vec!["a"]));
+        table.push_row(Row::new(vec!["b"]));
+        assert_eq!(table.rows, [Row::new(vec!["a"]), Row::new(vec!["b"])]);
+    }
+
+    #[test]
+    fn insert_row() {
+        let mut table = Table::default().rows([Row::new(vec!["b"]), Row::new(vec!["d"])]);
+        table.insert_row(0, Row::new(vec!["a"]));
+        table.insert_row(2, Row::new(vec!["c"]));
+        table.insert_row(4, Row::new(vec!["e"]));
+        assert_eq!(
+            table.rows,
+            ["a", "b", "c", "d", "e"].map(|c| Row::new(vec![c]))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_row_out_of_bounds() {
+        let mut table = Table::default();
+        table.insert_row(1, Row::new(vec!["a"]));
+    }
+
+    #[test]
+    fn remove_row() {
+        let mut table = Table::default().rows(["a", "b", "c"].map(|c| Row::new(vec![c])));
+        assert_eq!(table.remove_row(1), Row::new(vec!["b"]));
+        assert_eq!(table.remove_row(1), Row::new(vec!["c"]));
+        assert_eq!(table.remove_row(0), Row::new(vec!["a"]));
+        assert!(table.rows.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_row_out_of_bounds() {
+        let mut table = Table::default();
+        table.remove_row(0);
+    }
+
+    #[test]
+    fn pinned_top_rows() {
+        let pinned = Row::new(vec!["Total"]);
+        let table = Table::default().pinned_top_rows([pinned.clone()]);
+        assert_eq!(table.pinned_rows, [pinned]);
+    }
+
+    #[test]
+    fn sticky_header() {
+        assert!(!Table::default().scroll_header);
+        assert!(Table::default().sticky_header(false).scroll_header);
+        assert!(!Table::default().sticky_header(true).scroll_header);
+    }
+
+    #[test]
+    fn caption() {
+        let table = Table::default().caption("caption");
+        assert_eq!(table.caption, Some(Line::from("caption")));
+    }
+
+    #[test]
+    fn clear_rows() {
+        let header = Row::new(vec!["h"]);
+        let mut table = Table::default()
+            .rows([Row::new(vec!["a"])])
+            .header(header.clone());
+        table.clear_rows();
+        assert!(table.rows.is_empty());
+        assert_eq!(table.header, Some(header));
+    }
+
+    #[test]
+    fn column_spacing() {
+        let table = Table::default().column_spacing(2);
+        assert_eq!(table.column_spacing, 2);
+    }
+
+    #[test]
+    fn hover_style() {
+        let table = Table::default().hover_style(Style::new().blue());
+        assert_eq!(table.hover_style, Style::new().blue());
+    }
+
+    #[test]
+    fn expanded_row_height() {
+        let table = Table::new(vec![Row::new(vec!["a"]); 2], [Length(1)])
+            .expanded_row_height(|index| (index == 1).then_some(3));
+        assert_eq!(table.row_height(0), 1);
+        assert_eq!(table.row_height(1), 3);
+    }
+
+    #[test]
+    fn max_visible_rows() {
+        let table = Table::default().max_visible_rows(2);
+        assert_eq!(table.max_visible_rows, Some(2));
+    }
+
+    #[test]
+    fn incremental() {
+        let table = Table::default().incremental(true);
+        assert!(table.incremental);
+    }
+
+    #[test]
+    fn grid() {
+        let table = Table::default().grid(BorderType::Double);
+        assert_eq!(table.grid, Some(BorderType::Double));
+    }
+
+    #[test]
+    fn skeleton_rows() {
+        let style = Style::new().dark_gray();
+        let table = Table::default().skeleton_rows(3, style);
+        assert_eq!(table.skeleton_rows, Some((3, style)));
+    }
+
+    #[test]
+    fn caption_position() {
+        let table = Table::default().caption_position(CaptionPosition::BelowRows);
+        assert_eq!(table.caption_position, CaptionPosition::BelowRows);
+    }
+
+    #[test]
+    fn visible_columns() {
+        let table = Table::default().visible_columns([true, false]);
+        assert_eq!(table.visible_columns, [true, false]);
+    }
+
+    #[test]
+    fn filter() {
+        let table = Table::new(vec![Row::new(vec!["a"]), Row::new(vec!["b"])], [Length(1)])
+            .filter(|index, _| index == 1);
+        assert!(!table.is_row_displayed(0, &table.rows[0]));
+        assert!(table.is_row_displayed(1, &table.rows[1]));
+    }
+
+    #[test]
+    fn collapse_when_tiny() {
+        let table = Table::new(vec![Row::new(vec!["a"]), Row::new(vec!["b"])], [Length(1)])
+            .collapse_when_tiny(|rows| Line::from(rows.len().to_string()));
+        let Some(SharedFn(summary)) = table.collapsed_summary else {
+            panic!("the summary function is set");
+        };
+        assert_eq!(summary(&table.rows), Line::from("2"));
+    }
+
+    #[test]
+    fn empty_fill() {
+        let style = Style::new().dark_gray();
+        let table = Table::default().empty_fill('·', style);
+        assert_eq!(table.empty_fill, Some(('·', style)));
+    }
+
+    #[test]
+    fn direction() {
+        let table = Table::default().direction(HorizontalDirection::RightToLeft);
+        assert_eq!(table.direction, HorizontalDirection::RightToLeft);
+    }
+
+    #[test]
+    fn select_next_and_previous_skip_unselectable_rows() {
+        let rows = vec![
+            Row::new(vec!["Section 1"]).selectable(false),
+            Row::new(vec!["Cell1"]),
+            Row::new(vec!["Section 2"]).selectable(false),
+            Row::new(vec!["Cell2"]),
+            Row::new(vec!["Section 3"]).selectable(false),
+        ];
+        let table = Table::new(rows, [Length(5)]);
+        let mut state = TableState::new();
+        table.select_next(&mut state);
+        assert_eq!(state.selected(), Some(1));
+        table.select_next(&mut state);
+        assert_eq!(state.selected(), Some(3));
+        // there is no selectable row after the last one
+        table.select_next(&mut state);
+        assert_eq!(state.selected(), Some(3));
+        table.select_previous(&mut state);
+        assert_eq!(state.selected(), Some(1));
+        table.select_previous(&mut state);
+        assert_eq!(state.selected(), Some(1));
+
+        let mut state = TableState::new();
+        table.select_previous(&mut state);
+        assert_eq!(state.selected(), Some(3));
+    }
+
+    #[test]
+    fn select_next_counts_filtered_rows() {
+        let rows = (0..6).map(|i| Row::new(vec![i.to_string()]).selectable(i != 2));
+        let table = Table::new(rows, [Length(5)]).filter(|index, _| index % 2 == 0);
+        // the displayed rows are 0, 2 and 4, and the row 2 is not selectable
+        let mut state = TableState::new().with_selected(0);
+        table.select_next(&mut state);
+        assert_eq!(state.selected(), Some(2));
+    }
+
+    #[test]
+    fn select_by() {
+        let names = ["Alice", "Bob", "Carol", "Dave"];
+        let table = Table::new(names.map(|name| Row::new(vec![name])), [Length(5)])
+            .filter(|index, _| index != 1);
+        let mut state = TableState::new().with_selected(0);
+        // the rows are counted among the displayed rows Alice, Carol and Dave
+        let index = table.select_by(&mut state, |row| {
+            row.cell_text(0).as_deref() == Some("Dave")
+        });
+        assert_eq!(index, Some(2));
+        assert_eq!(state.selected(), Some(2));
+
+        // the selection is kept when no row matches
+        let index = table.select_by(&mut state, |row| row.cell_text(0).as_deref() == Some("Bob"));
+        assert_eq!(index, None);
+        assert_eq!(state.selected(), Some(2));
+    }
+
+    #[test]
+    fn to_delimited() {
+        let table = Table::new(
+            vec![
+                Row::new(vec![Cell::from("Apple"), Cell::from("1,50")]),
+                Row::new(vec![
+                    Cell::from("Big\n\"Melon\""),
+                    Cell::from(Line::from(vec!["3".red(), ",00".into()])),
+                ]),
+            ],
+            [Length(5), Length(5)],
+        )
+        .header(Row::new(vec!["Name", "Price"]));
+        assert_eq!(
+            table.to_delimited('\t'),
+            "Name\tPrice\nApple\t1,50\n\"Big \"\"Melon\"\"\"\t3,00"
+        );
+        assert_eq!(
+            table.to_delimited(','),
+            "Name,Price\nApple,\"1,50\"\n\"Big \"\"Melon\"\"\",\"3,00\""
+        );
+    }
+
+    #[test]
+    fn edge_padding() {
+        let table = Table::default().edge_padding(2);
+        assert_eq!(table.edge_padding, 2);
+    }
+
+    #[test]
+    fn distribute_remainder() {
+        let table = Table::default().distribute_remainder(true);
+        assert!(table.distribute_remainder);
+    }
+
+    #[test]
+    fn min_width_for_spacing() {
+        let table = Table::default().min_width_for_spacing(40);
+        assert_eq!(table.min_width_for_spacing, 40);
+    }
+
+    #[test]
+    fn block() {
+        let block = Block::default().title("Table").borders(Borders::ALL);
+        let table = Table::default().block(block.clone());
+        assert_eq!(table.block, Some(block));
+    }
+
+    #[test]
+    fn header() {
+        let header = Row::new(vec![Cell::from("")]);
+        let table = Table::default().header(header.clone());
+        assert_eq!(table.header, Some(header));
+    }
+
+    #[test]
+    fn try_new() {
+        let rows = vec![Row::new(vec!["a", "b"]), Row::new(vec!["c"])];
+        let table = Table::try_new(rows.clone(), [Length(1), Percentage(100)]);
+        assert_eq!(
+            table,
+            Ok(Table::new(rows.clone(), [Length(1), Percentage(100)]))
+        );
+        assert_eq!(
+            Table::try_new(rows.clone(), [Length(1)]),
+            Err(TableError::WidthsMismatch {
+                widths: 1,
+                columns: 2
+            })
+        );
+        assert_eq!(
+            Table::try_new(rows, [Length(1), Percentage(101)]),
+            Err(TableError::InvalidPercentage(101))
+        );
+        // the number of widths is not checked without rows
+        assert!(Table::try_new(vec![], [Length(1)]).is_ok());
+    }
+
+    #[test]
+    fn min_size() {
+        let rows = vec![
+            Row::new(vec!["a", "b", "c"]).height(2).bottom_margin(1),
+            Row::new(vec!["d", "e", "f"]),
+            Row::new(vec!["g", "h", "i"]),
+        ];
+        let widths = [Length(5), Min(3), Percentage(50)];
+        let table = Table::new(rows, widths).column_spacing(2);
+        // 5 + 3 + 0 wide columns and 2 spaces of 2
+        assert_eq!(table.min_size(0), (12, 0));
+        assert_eq!(table.min_size(1), (12, 3));
+        assert_eq!(table.min_size(2), (12, 4));
+        assert_eq!(table.min_size(10), (12, 5));
+        // the rows hidden by the filter are not counted
+        let filtered = table.clone().filter(|index, _| index != 0);
+        assert_eq!(filtered.min_size(1), (12, 1));
+        assert_eq!(filtered.min_size(10), (12, 2));
+
+        let table = table
+            .header(Row::new(vec!["Head"]).bottom_margin(1))
+            .pinned_top_rows([Row::new(vec!["Pinned"])])
+            .caption(Line::from("Caption"))
+            .highlight_symbol(">> ")
+            .block(Block::default().borders(Borders::ALL));
+        // 3 wide symbol and 2 borders
+        assert_eq!(table.min_size(1), (17, 9));
+        let table = table.highlight_spacing(HighlightSpacing::Never);
+        assert_eq!(table.min_size(1).0, 14);
+    }
+
+    #[test]
+    fn cell_style() {
+        let table = Table::default().cell_style(Style::new().yellow());
+        assert_eq!(table.cell_style, Style::new().yellow());
+    }
+
+    #[test]
+    fn alternating_column_styles() {
+        let table = Table::default().alternating_column_styles(Style::new(), Style::new().blue());
+        assert_eq!(
+            table.alternating_column_styles,
+            Some((Style::new(), Style::new().blue()))
+        );
+    }
+
+    #[test]
+    fn header_separator() {
+        let table = Table::default().header_separator('─');
+        assert_eq!(table.header_separator, Some('─'));
+    }
+
+    #[test]
+    fn column_styles() {
+        let table = Table::default().column_styles([Style::new(), Style::new().green()]);
+        assert_eq!(table.column_styles, [Style::new(), Style::new().green()]);
+    }
+
+    #[test]
+    fn highlight_style() {
+        let style = Style::default().red().italic();
+        let table = Table::default().highlight_style(style);
+        assert_eq!(table.highlight_style, style);
+    }
+
+    #[test]
+    fn highlight_style_shorthands() {
+        let table = Table::default()
+            .highlight_style(Style::new().red().italic())
+            .highlight_bg(Color::Blue)
+            .highlight_modifier(Modifier::BOLD);
+        assert_eq!(
+            table.highlight_style,
+            Style::new().red().on_blue().italic().bold()
+        );
+        let table = table.highlight_fg(Color::Green);
+        assert_eq!(
+            table.highlight_style,
+            Style::new().green().on_blue().italic().bold()
+        );
+    }
+
+    #[test]
+    fn eq_and_hash_include_visual_fields() {
+        use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+        fn hash(table: &Table) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            table.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let table = Table::new(vec![Row::new(vec!["Cell1"])], [Length(5)]);
+        assert_eq!(table, table.clone());
+        assert_eq!(hash(&table), hash(&table.clone()));
+
+        let changed = [
+            table.clone().highlight_style(Style::new().red()),
+            table.clone().highlight_symbol(">"),
+            table.clone().highlight_spacing(HighlightSpacing::Always),
+            table.clone().style(Style::new().red()),
+            table.clone().cell_style(Style::new().red()),
+            table.clone().column_spacing(2),
+            table.clone().flex(Flex::LastTakesRemainder),
+            table.clone().widths([Length(6)]),
+            table
+                .clone()
+                .rows(vec![Row::new(vec!["Cell1"]).style(Style::new().red())]),
+        ];
+        for other in changed {
+            assert_ne!(table, other);
+            assert_ne!(hash(&table), hash(&other));
+        }
+    }
+
+    #[test]
+    fn highlight_symbol_owned() {
+        let symbol = String::from(">") + ">";
+        let table = Table::default().highlight_symbol(symbol);
+        assert_eq!(table.highlight_symbol.as_deref(), Some(">>"));
+    }
+
+    #[test]
+    fn highlight_symbol() {
+        let table = Table::default().highlight_symbol(">>");
+        assert_eq!(table.highlight_symbol, Some(Cow::Borrowed(">>")));
+    }
+
+    #[test]
+    fn highlight_symbol_use_highlight_style() {
+        let table = Table::default();
+        assert!(!table.highlight_symbol_use_highlight_style);
+        let table = table.highlight_symbol_use_highlight_style(true);
+        assert!(table.highlight_symbol_use_highlight_style);
+    }
+
+    #[test]
+    fn highlight_symbol_repeat() {
+        let table = Table::default().highlight_symbol_repeat(true);
+        assert!(table.highlight_symbol_repeat);
+    }
+
+    #[test]
+    fn highlight_matches() {
+        let style = Style::new().on_yellow();
+        let table = Table::default()
+            .highlight_matches("ell", style)
+            .highlight_matches_ignore_case(true);
+        assert_eq!(table.match_query, Some(Cow::Borrowed("ell")));
+        assert_eq!(table.match_style, style);
+        assert!(table.match_ignore_case);
+    }
+
+    #[test]
+    fn highlight_symbol_style() {
+        let style = Style::default().yellow().on_red();
+        let table = Table::default().highlight_symbol_style(style);
+        assert_eq!(table.highlight_symbol_style, style);
+    }
+
+    #[test]
+    fn on_invalid_selection() {
+        let table = Table::default().on_invalid_selection(InvalidSelection::Clear);
+        assert_eq!(table.invalid_selection, InvalidSelection::Clear);
+    }
+
+    #[test]
+    fn anchor() {
+        let table = Table::default().anchor(Anchor::Bottom);
+        assert_eq!(table.anchor, Anchor::Bottom);
+    }
+
+    #[test]
+    fn highlight_spacing() {
+        let table = Table::default().highlight_spacing(HighlightSpacing::Always);
+        assert_eq!(table.highlight_spacing, HighlightSpacing::Always);
+    }
+
+    #[test]
+    fn highlight_theme() {
+        let theme = HighlightTheme {
+            symbol: ">> ".into(),
+            style: Style::new().yellow(),
+            spacing: HighlightSpacing::Always,
+        };
+        let table = Table::default().highlight_theme(theme);
+        assert_eq!(table.highlight_symbol, Some(">> ".into()));
+        assert_eq!(table.highlight_style, Style::new().yellow());
+        assert_eq!(table.highlight_spacing, HighlightSpacing::Always);
+    }
+
+    #[test]
+    fn selection_width_override() {
+        let table = Table::default().selection_width_override(2);
+        assert_eq!(table.selection_width_override, Some(2));
+    }
+
+    #[test]
+    fn east_asian_width() {
+        let table = Table::default().east_asian_width(true);
+        assert!(table.east_asian_width);
+    }
+
+    #[test]
+    fn str_width() {
+        let ambiguous = "→①○";
+        assert_eq!(Table::default().str_width(ambiguous), 3);
+        let table = Table::default().east_asian_width(true);
+        assert_eq!(table.str_width(ambiguous), 6);
+        // characters with a non ambiguous width are measured the same way
+        assert_eq!(table.str_width("ab表"), 4);
+    }
+
+    #[test]
+    fn selection_width_with_east_asian_width() {
+        let state = TableState::new().with_selected(0);
+        let table = Table::default().highlight_symbol("→ ");
+        assert_eq!(table.selection_width(&state), 2);
+        assert_eq!(table.east_asian_width(true).selection_width(&state), 3);
+    }
+
+    #[test]
+    fn min_column_width() {
+        let table = Table::default().min_column_width(3);
+        assert_eq!(table.min_column_width, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn table_invalid_percentages() {
+        let _ = Table::default().widths([Constraint::Percentage(110)]);
+    }
+
+    #[test]
+    fn get_widths_and_uses_equal_widths() {
+        let table = Table::default();
+        assert!(table.get_widths().is_empty());
+        assert!(table.uses_equal_widths());
+
+        let table = table.widths([Constraint::Length(5), Constraint::Min(0)]);
+        assert_eq!(
+            table.get_widths(),
+            [Constraint::Length(5), Constraint::Min(0)]
+        );
+        assert!(!table.uses_equal_widths());
+    }
+
+    #[test]
+    fn widths_conversions() {
+        let array = [Constraint::Percentage(100)];
+        let table = Table::new(vec![], array);
+        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "array");
+
+        let array_ref = &[Constraint::Percentage(100)];
+        let table = Table::new(vec![], array_ref);
+        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "array ref");
+
+        let vec = vec![Constraint::Percentage(100)];
+        let slice = vec.as_slice();
+        let table = Table::new(vec![], slice);
+        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "slice");
+
+        let vec = vec![Constraint::Percentage(100)];
+        let table = Table::new(vec![], vec);
+        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "vec");
+
+        let vec_ref = &vec![Constraint::Percentage(100)];
+        let table = Table::new(vec![], vec_ref);
+        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "vec ref");
+    }
+
+    #[test]
+    fn resolve_cell_style() {
+        let rows = vec![
+            Row::new(vec![Cell::new("a").red(), Cell::new("b")]).on_blue(),
+            Row::new(vec!["c"]),
+        ];
+        let table = Table::new(rows, [Length(1), Length(1)])
+            .style(Style::new().green().on_black().bold())
+            .column_styles([Style::new(), Style::new().yellow()]);
+        let state = TableState::default();
+        // the cell sets the fg, the row sets the bg, the table sets the modifier
+        assert_eq!(
+            table.resolve_cell_style(&state, 0, 0),
+            Style::new().red().on_blue().bold()
+        );
+        // the column sets the fg
+        assert_eq!(
+            table.resolve_cell_style(&state, 0, 1),
+            Style::new().yellow().on_blue().bold()
+        );
+        assert_eq!(
+            table.resolve_cell_style(&state, 1, 0),
+            Style::new().green().on_black().bold()
+        );
+
+        // the hover and highlight styles are patched last
+        let table = table
+            .row_decorator(|_, _| Style::new().italic())
+            .hover_style(Style::new().on_gray())
+            .highlight_style(Style::new().magenta());
+        let mut state = TableState::default().with_selected(0);
+        state.set_hovered(Some(0));
+        assert_eq!(
+            table.resolve_cell_style(&state, 0, 0),
+            Style::new().magenta().on_gray().bold().italic()
+        );
+    }
+
+    #[test]
+    fn scroll_to_keeps_selection() {
+        let rows = (0..100).map(|i| Row::new(vec![i.to_string()]));
+        let table = Table::new(rows, [Length(2)]);
+        let area = Rect::new(0, 0, 2, 10);
+        let mut buf = Buffer::empty(area);
+        let mut state = TableState::new().with_selected(0);
+        state.scroll_to(50);
+        StatefulWidget::render(table.clone(), area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 50);
+        assert_eq!(state.visible_rows(), (50, 60));
+        assert_eq!(state.selected(), Some(0));
+
+        // the last rows fill the table
+        state.scroll_to(95);
+        StatefulWidget::render(table.clone(), area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 90);
+
+        // selecting a row scrolls it into view again
+        state.select(Some(0));
+        StatefulWidget::render(table, area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn visible_rows() {
+        let rows = (0..50).map(|i| Row::new(vec![i.to_string()]));
+        let table = Table::new(rows, [Length(2)]).header(Row::new(vec!["H"]));
+        let visible = |area: Rect, state: &TableState| {
+            table
+                .visible_rows(area, state)
+                .map(|(i, row)| {
+                    assert_eq!(row, &table.rows[i]);
+                    i
+                })
+                .collect_vec()
+        };
+        let area = Rect::new(0, 0, 2, 6);
+        assert_eq!(visible(area, &TableState::default()), [0, 1, 2, 3, 4]);
+        let state = TableState::default().with_offset(20).with_selected(22);
+        assert_eq!(visible(area, &state), [20, 21, 22, 23, 24]);
+        let state = TableState::default().with_offset(20).with_selected(40);
+        assert_eq!(visible(area, &state), [36, 37, 38, 39, 40]);
+        // the header scrolls away to leave its space to the selected row
+        assert_eq!(visible(Rect::new(0, 0, 2, 1), &state), [40]);
+        assert_eq!(visible(Rect::new(0, 0, 2, 0), &state), []);
+
+        // the state is not modified
+        let mut rendered = state.clone();
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(table.clone(), area, &mut buf, &mut rendered);
+        assert_eq!(state.offset, 20);
+        assert_eq!(rendered.offset, 36);
+    }
+
+    #[test]
+    fn reserves_selection_column() {
+        let unselected = TableState::default();
+        let selected = TableState::default().with_selected(0);
+        let table = Table::default().highlight_spacing(HighlightSpacing::Always);
+        assert!(table.reserves_selection_column(&unselected));
+        assert!(table.reserves_selection_column(&selected));
+        let table = Table::default().highlight_spacing(HighlightSpacing::WhenSelected);
+        assert!(!table.reserves_selection_column(&unselected));
+        assert!(table.reserves_selection_column(&selected));
+        let table = Table::default().highlight_spacing(HighlightSpacing::Never);
+        assert!(!table.reserves_selection_column(&unselected));
+        assert!(!table.reserves_selection_column(&selected));
+    }
+
+    /// Asserts that [`Table::row_at_y`] finds the rows on the lines where the last render drew
+    /// them, the lines of each row showing its name in its first cell.
+    #[track_caller]
+    fn assert_row_at_drawn_lines(table: &Table, state: &TableState, buf: &Buffer, names: &[&str]) {
+        let area = buf.area;
+        let found = (area.top()..area.bottom())
+            .map(|y| table.row_at_y(state, y))
+            .collect_vec();
+        let drawn = (area.top()..area.bottom())
+            .map(|y| {
+                let symbol = buf.get(state.rows_area.x, y).symbol();
+                let in_rows = y >= state.rows_area.top() && y < state.rows_area.bottom();
+                names.iter().position(|&name| in_rows && name == symbol)
+            })
+            .collect_vec();
+        assert_eq!(found, drawn);
+    }
+
+    #[test]
+    fn row_at_y() {
+        let rows = vec![
+            Row::new(vec!["a\na"]).height(2).bottom_margin(1),
+            Row::new(vec!["b"]),
+            Row::new(vec!["c\nc\nc"]).height(3),
+            Row::new(vec!["d"]).bottom_margin(2),
+        ];
+        let table = Table::new(rows, [Length(1)]);
+        let area = Rect::new(0, 5, 1, 6);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 12));
+        let render = |table: &Table, state: &mut TableState, buf: &mut Buffer| {
+            buf.reset();
+            StatefulWidget::render(table.clone(), area, buf, state);
+        };
+        let rows_at = |table: &Table, state: &TableState| {
+            (4..12).map(|y| table.row_at_y(state, y)).collect_vec()
+        };
+
+        // the third row does not fit below the first two rows
+        let mut state = TableState::default();
+        assert_eq!(rows_at(&table, &state), [None; 8], "not rendered yet");
+        render(&table, &mut state, &mut buf);
+        assert_eq!(
+            rows_at(&table, &state),
+            [None, Some(0), Some(0), None, Some(1), None, None, None]
+        );
+
+        // the rows are walked from the offset
+        state.scroll_to(2);
+        render(&table, &mut state, &mut buf);
+        assert_eq!(
+            rows_at(&table, &state),
+            [None, Some(2), Some(2), Some(2), Some(3), None, None, None]
+        );
+
+        // bottom anchored rows start below the free space
+        let table = table.anchor(Anchor::Bottom);
+        let mut state = TableState::default().with_selected(1);
+        render(&table, &mut state, &mut buf);
+        assert_eq!(
+            rows_at(&table, &state),
+            [None, None, None, Some(0), Some(0), None, Some(1), None]
+        );
+    }
+
+    #[test]
+    fn row_at_y_follows_the_render() {
+        let names = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+        let rows = names.map(|name| Row::new(vec![format!("{name}\n{name}")]).height(2));
+        let table = Table::new(rows, [Length(1)]).header(Row::new(vec!["H"]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 6));
+        let mut render = |table: &Table, state: &mut TableState| {
+            buf.reset();
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, state);
+            buf.clone()
+        };
+
+        // the rows hidden by the filter are skipped
+        let filtered = table.clone().filter(|index, _| index % 2 == 1);
+        let displayed = ["b", "d", "f", "h", "j"];
+        let mut state = TableState::default().with_selected(3);
+        let buf = render(&filtered, &mut state);
+        assert_row_at_drawn_lines(&filtered, &state, &buf, &displayed);
+
+        // the first row is partially scrolled above the rows
+        let mut state = TableState::default()
+            .with_selected(2)
+            .with_offset(1)
+            .with_line_offset(1);
+        let buf = render(&filtered, &mut state);
+        assert_eq!(state.line_offset(), 1);
+        assert_row_at_drawn_lines(&filtered, &state, &buf, &displayed);
+
+        // the header scrolled away leaves its line to the rows
+        let scrolled = table.clone().sticky_header(false);
+        let mut state = TableState::default().with_selected(5);
+        let buf = render(&scrolled, &mut state);
+        assert_eq!(state.last_header_area(), Rect::default());
+        assert_row_at_drawn_lines(&scrolled, &state, &buf, &names);
+
+        // the overflow markers take the first and the last lines
+        let marked = table.clone().overflow_markers(true);
+        let mut state = TableState::default().with_selected(4);
+        let buf = render(&marked, &mut state);
+        assert_row_at_drawn_lines(&marked, &state, &buf, &names);
+
+        // the selected row is centered
+        let mut state = TableState::default().with_selected(4);
+        state.set_scroll_mode(ScrollMode::CenterSelection);
+        let buf = render(&table, &mut state);
+        assert_row_at_drawn_lines(&table, &state, &buf, &names);
+
+        // the rows are anchored at the bottom
+        let anchored = table.anchor(Anchor::Bottom);
+        let mut state = TableState::default();
+        let buf = render(&anchored, &mut state);
+        assert_row_at_drawn_lines(&anchored, &state, &buf, &names);
+    }
+
+    #[test]
+    fn row_at_y_with_tall_rows_at_the_bottom() {
+        let rows = vec![Row::new(vec!["a"]).height(u16::MAX)];
+        let table = Table::new(rows, [Length(1)]).anchor(Anchor::Bottom);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 3));
+        let mut state = TableState::default();
+        StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+        let rows_at = (0..3).map(|y| table.row_at_y(&state, y)).collect_vec();
+        assert_eq!(rows_at, [Some(0); 3]);
+    }
+
+    #[test]
+    fn row_at_y_in() {
+        let rows = vec![
+            Row::new(vec!["a\na"]).height(2).bottom_margin(1),
+            Row::new(vec!["b"]),
+            Row::new(vec!["c\nc\nc"]).height(3),
+            Row::new(vec!["d"]).bottom_margin(2),
+            Row::new(vec!["e"]),
+        ];
+        let table = Table::new(rows, [Length(1)]);
+        let rows_area = Rect::new(0, 5, 1, 6);
+        let rows_at = |table: &Table, state: &TableState| {
+            (4..12)
+                .map(|y| table.row_at_y_in(rows_area, state, y))
+                .collect_vec()
+        };
+
+        let state = TableState::default();
+        assert_eq!(
+            rows_at(&table, &state),
+            [
+                None,
+                Some(0),
+                Some(0),
+                None,
+                Some(1),
+                Some(2),
+                Some(2),
+                None
+            ]
+        );
+
+        // the rows are walked from the offset, skipping the line offset of the first row
+        let state = TableState::default().with_offset(2).with_line_offset(1);
+        assert_eq!(
+            rows_at(&table, &state),
+            [None, Some(2), Some(2), Some(3), None, None, Some(4), None]
+        );
+
+        // the rows hidden by the filter are skipped
+        let table = table.filter(|index, _| index != 1);
+        let state = TableState::default();
+        assert_eq!(
+            rows_at(&table, &state),
+            [
+                None,
+                Some(0),
+                Some(0),
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_zone_width() {
+        let table = Table::default().expand_zone_width(2);
+        assert_eq!(table.expand_zone_width, 2);
+    }
+
+    #[test]
+    fn expand_hit() {
+        let rows = vec![
+            Row::new(vec!["▸ a", "b"]).height(2),
+            Row::new(vec!["▸ c", "d"]),
+        ];
+        let table = Table::new(rows, [Length(3), Length(1)]).highlight_symbol(">");
+        let area = Rect::new(10, 5, 6, 3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+        let mut render = |table: &Table, state: &mut TableState| {
+            StatefulWidget::render(table.clone(), area, &mut buf, state);
+        };
+        let mut state = TableState::default();
+        render(&table, &mut state);
+        // the zone is disabled by default
+        assert_eq!(table.expand_hit(&state, 10, 5), None);
+
+        let table = table.expand_zone_width(2);
+        let hits = |state: &TableState, y: u16| {
+            (9..16).map(|x| table.expand_hit(state, x, y)).collect_vec()
+        };
+        assert_eq!(
+            hits(&state, 6),
+            [None, Some(0), Some(0), None, None, None, None]
+        );
+        assert_eq!(
+            hits(&state, 7),
+            [None, Some(1), Some(1), None, None, None, None]
+        );
+        // the zone starts after the selection column
+        let mut state = TableState::default().with_selected(0);
+        render(&table, &mut state);
+        assert_eq!(
+            hits(&state, 5),
+            [None, None, Some(0), Some(0), None, None, None]
+        );
+        // no row is displayed below the rows
+        assert_eq!(table.expand_hit(&state, 11, 8), None);
+    }
+
+    #[test]
+    fn expand_hit_with_filter_and_line_offset() {
+        let rows = (0..6).map(|i| Row::new(vec![format!("▸{i}\n {i}")]).height(2));
+        let table = Table::new(rows, [Length(2)])
+            .filter(|index, _| index % 2 == 1)
+            .expand_zone_width(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+        // the displayed rows are 1, 3 and 5, the row 3 is scrolled up by one line
+        let mut state = TableState::default()
+            .with_selected(1)
+            .with_offset(1)
+            .with_line_offset(1);
+        StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(vec![" 3", "▸5", " 5"]));
+        let hits = (0..3).map(|y| table.expand_hit(&state, 0, y)).collect_vec();
+        assert_eq!(hits, [Some(1), Some(2), Some(2)]);
+        assert_eq!(table.expand_hit(&state, 1, 1), None);
+    }
+
+    #[cfg(test)]
+    mod render {
+        use super::*;
+        use crate::{
+            assert_buffer_eq,
+            widgets::{
+                block::{Position, Title},
+                Borders,
+            },
+        };
+
+        #[test]
+        fn render_empty_area() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, vec![Constraint::Length(5); 2]);
+            Widget::render(table, Rect::new(0, 0, 0, 0), &mut buf);
+            assert_buffer_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
+        }
+
+        #[test]
+        fn render_default() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let table = Table::default();
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            assert_buffer_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
+        }
+
+        #[test]
+        fn render_with_block() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let block = Block::new().borders(Borders::ALL).title("Block");
+            let table = Table::new(rows, vec![Constraint::Length(5); 2]).block(block);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "┌Block────────┐",
+                "│Cell1 Cell2  │",
+                "└─────────────┘",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_block_bottom_title() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let block = Block::new()
+                .borders(Borders::ALL)
+                .title("Top")
+                .title(Title::from("Bottom").position(Position::Bottom));
+            let table = Table::new(rows, vec![Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .block(block);
+            Widget::render(table, Rect::new(0, 0, 15, 5), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "┌Top──────────┐",
+                "│Head1 Head2  │",
+                "│Cell1 Cell2  │",
+                "│Cell3 Cell4  │",
+                "└Bottom───────┘",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_borderless_block_bottom_title() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let block = Block::new().title(Title::from("Bottom").position(Position::Bottom));
+            let table = Table::new(rows, vec![Constraint::Length(5); 2]).block(block);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+                "Bottom         ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let header = Row::new(vec!["Head1", "Head2"]);
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]).header(header);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_full_width_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Updated on Dec 28"]).full_width(true),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .header(Row::new(vec!["Full width header"]).full_width(true))
+                .pinned_top_rows([Row::new(vec!["Updated on Dec 28"])]);
+            Widget::render(table, Rect::new(0, 0, 17, 4), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Full width header",
+                "Updat            ",
+                "Cell1 Cell2 Cell3",
+                "Updated on Dec 28",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_max_visible_rows() {
+            let render = |state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+                let rows = (1..=4).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)])
+                    .header(Row::new(vec!["Head"]))
+                    .max_visible_rows(2);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 5), &mut buf, state);
+                buf
+            };
+            let expected = Buffer::with_lines(vec![
+                "Head           ",
+                "Cell1          ",
+                "Cell2          ",
+                "               ",
+                "               ",
+            ]);
+            assert_buffer_eq!(render(&mut TableState::new()), expected);
+
+            // the selected row is scrolled into the visible rows
+            let mut state = TableState::new().with_selected(3);
+            let expected = Buffer::with_lines(vec![
+                "Head           ",
+                "Cell3          ",
+                "Cell4          ",
+                "               ",
+                "               ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+            assert_eq!(state.offset, 2);
+        }
+
+        #[test]
+        fn render_with_highlighted_matches() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec![
+                    Cell::from(Line::from(vec!["CE".red(), "LL3".into()])),
+                    "cell".into(),
+                ]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Hell"]))
+                .highlight_matches("ell", Style::new().on_yellow());
+            Widget::render(table.clone(), Rect::new(0, 0, 11, 2), &mut buf);
+            let mut expected = Buffer::with_lines(vec!["Hell       ", "Cell1 Cell2"]);
+            // the header is not highlighted
+            expected.set_style(Rect::new(1, 1, 3, 1), Style::new().on_yellow());
+            expected.set_style(Rect::new(7, 1, 3, 1), Style::new().on_yellow());
+            assert_buffer_eq!(buf, expected);
+
+            // the match spans several spans of the line
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let table = table
+                .header(Row::default().height(0))
+                .highlight_matches_ignore_case(true);
+            let mut state = TableState::new().with_offset(1).with_selected(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 11, 1), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec!["CELL3 cell "]);
+            expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+            expected.set_style(Rect::new(1, 0, 3, 1), Style::new().on_yellow());
+            expected.set_style(Rect::new(7, 0, 3, 1), Style::new().on_yellow());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_lazy_cells() {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let rows = (1..=5).map(|i| {
+                let calls = Arc::clone(&calls);
+                Row::new(vec![Cell::from_fn(move || {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    format!("Cell{i}")
+                })])
+            });
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let mut state = TableState::new().with_offset(2).with_selected(2);
+            StatefulWidget::render(table, Rect::new(0, 0, 5, 2), &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Cell3", "Cell4"]));
+            // the content of the rows that are not visible is not computed
+            assert_eq!(calls.load(Ordering::Relaxed), 2);
+        }
+
+        #[test]
+        fn render_with_layout() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec![
+                    Cell::from("Cell3"),
+                    Cell::from(vec!["Cell4".into(), "Line2".into()]),
+                ])
+                .height(2),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(1);
+            let layout = table.render_with_layout(buf.area, &mut buf, &mut state);
+            let cell = |row, col, rect, text: &str| CellLayout {
+                row,
+                col,
+                rect,
+                text: text.to_string(),
+            };
+            assert_eq!(
+                layout.cells,
+                [
+                    cell(0, 0, Rect::new(2, 1, 5, 1), "Cell1"),
+                    cell(0, 1, Rect::new(8, 1, 5, 1), "Cell2"),
+                    cell(1, 0, Rect::new(2, 2, 5, 2), "Cell3"),
+                    cell(1, 1, Rect::new(8, 2, 5, 2), "Cell4\nLine2"),
+                ]
+            );
+        }
+
+        #[test]
+        fn render_with_grid() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+            let rows = vec![Row::new(vec!["a", "b"]), Row::new(vec!["c", "d"])];
+            let table = Table::new(rows, [Constraint::Length(1); 2]).grid(BorderType::Plain);
+            Widget::render(table, Rect::new(0, 0, 5, 5), &mut buf);
+            let expected = Buffer::with_lines(vec!["┌─┬─┐", "│a│b│", "├─┼─┤", "│c│d│", "└─┴─┘"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_zero_height_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let rows = vec![Row::new(vec!["a"]).height(0), Row::new(vec!["b"])];
+            let table = Table::new(rows, [Constraint::Length(3)]);
+            Widget::render(table, Rect::new(0, 0, 3, 3), &mut buf);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["b  ", "   ", "   "]));
+        }
+
+        #[test]
+        fn render_with_grid_header_and_block() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 10));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .block(Block::default().borders(Borders::ALL))
+                .caption(Line::from("2 rows"))
+                .highlight_symbol(">")
+                .grid(BorderType::Double);
+            let mut state = TableState::new().with_selected(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 10), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "┌─────────────┐",
+                "│╔══════╦════╗│",
+                "│║ Head1║Head║│",
+                "│╠══════╬════╣│",
+                "│║ Cell1║Cell║│",
+                "│╠══════╬════╣│",
+                "│║>Cell3║Cell║│",
+                "│╚══════╩════╝│",
+                "│2 rows       │",
+                "└─────────────┘",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_skeleton_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .skeleton_rows(2, Style::new().dark_gray());
+            Widget::render(table, Rect::new(0, 0, 11, 4), &mut buf);
+            let mut expected = Buffer::with_lines(vec![
+                "Head1 Head2",
+                "▄▄▄▄▄ ▄▄▄▄▄",
+                "▄▄▄▄▄ ▄▄▄▄▄",
+                "           ",
+            ]);
+            for y in 1..3 {
+                expected.set_style(Rect::new(0, y, 5, 1), Style::new().dark_gray());
+                expected.set_style(Rect::new(6, y, 5, 1), Style::new().dark_gray());
+            }
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_skeleton_rows_ignored_with_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let table = Table::new(vec![Row::new(vec!["Cell1"])], [Constraint::Length(5)])
+                .skeleton_rows(2, Style::new().dark_gray());
+            Widget::render(table, Rect::new(0, 0, 5, 2), &mut buf);
+            let expected = Buffer::with_lines(vec!["Cell1", "     "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_hidden_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2", "Cell3"])];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .header(Row::new(vec!["Head1", "Head2", "Head3"]))
+                .column_styles([Style::new(), Style::new(), Style::new().red()])
+                .visible_columns([true, false, true]);
+            let mut state = TableState::default();
+            StatefulWidget::render(table, Rect::new(0, 0, 17, 2), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec!["Head1 Head3      ", "Cell1 Cell3      "]);
+            expected.set_style(Rect::new(6, 0, 5, 2), Style::new().red());
+            assert_buffer_eq!(buf, expected);
+            assert_eq!(state.last_column_rects()[1], Rect::new(6, 1, 0, 1));
+            assert_eq!(state.last_column_rects()[2], Rect::new(6, 1, 5, 1));
+        }
+
+        #[test]
+        fn render_with_filter() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let rows = (0..10).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .highlight_symbol(">")
+                .filter(|index, _| index % 2 == 0);
+            // the selection and the offset are positions among the displayed rows
+            let mut state = TableState::new().with_selected(3);
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 7, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![" Row2  ", " Row4  ", ">Row6  "]);
+            assert_buffer_eq!(buf, expected);
+            assert_eq!(state.offset(), 1);
+            assert_eq!(state.visible_rows(), (1, 4));
+
+            let visible = table
+                .visible_rows(Rect::new(0, 0, 7, 3), &state)
+                .map(|(index, row)| (index, row.clone()))
+                .collect_vec();
+            assert_eq!(
+                visible,
+                [
+                    (1, Row::new(vec!["Row2"])),
+                    (2, Row::new(vec!["Row4"])),
+                    (3, Row::new(vec!["Row6"])),
+                ]
+            );
+        }
+
+        #[test]
+        fn render_collapsed_when_tiny() {
+            let rows = (0..12).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .header(Row::new(vec!["Head"]))
+                .filter(|index, _| index != 0)
+                .collapse_when_tiny(|rows| Line::from(format!("{} items", rows.len())));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            Widget::render(table.clone(), buf.area, &mut buf);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["11 items  "]));
+
+            // the table is rendered when it is tall enough
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+            Widget::render(table, buf.area, &mut buf);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Head      ", "Row1      "]));
+        }
+
+        #[test]
+        fn render_with_filter_clamps_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let rows = (0..10).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)]).filter(|index, _| index > 7);
+            let mut state = TableState::new().with_selected(5);
+            StatefulWidget::render(table, Rect::new(0, 0, 7, 3), &mut buf, &mut state);
+            assert_eq!(state.selected(), Some(1));
+        }
+
+        #[test]
+        fn render_with_empty_fill() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+            let table = Table::new(vec![Row::new(vec!["Cell1"])], [Constraint::Length(5)])
+                .header(Row::new(vec!["Head1"]))
+                .empty_fill('·', Style::new().dark_gray());
+            Widget::render(table, Rect::new(0, 0, 5, 4), &mut buf);
+            let mut expected = Buffer::with_lines(vec!["Head1", "Cell1", "·····", "·····"]);
+            expected.set_style(Rect::new(0, 2, 5, 2), Style::new().dark_gray());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_line_offset() {
+            let rows = vec![
+                Row::new(vec![Text::from(vec![
+                    "A1".into(),
+                    "A2".into(),
+                    "A3".into(),
+                ])])
+                .height(3),
+                Row::new(vec!["B"]),
+                Row::new(vec!["C"]),
+                Row::new(vec!["D"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(2)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+            let mut state = TableState::new().with_line_offset(1);
+            let layout = table.render_with_layout(Rect::new(0, 0, 2, 4), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec!["A2", "A3", "B ", "C "]);
+            assert_buffer_eq!(buf, expected);
+            assert_eq!(state.visible_rows(), (0, 3));
+            assert_eq!(state.line_offset(), 1);
+            assert_eq!(layout.cells[0].rect, Rect::new(0, 0, 2, 2));
+            assert_eq!(layout.cells[1].rect, Rect::new(0, 2, 2, 1));
+        }
+
+        #[test]
+        fn render_with_line_offset_reset_when_scrolled() {
+            let rows = (0..6).map(|i| Row::new(vec![format!("R{i}")]).height(2));
+            let table = Table::new(rows, [Constraint::Length(2)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+            // the line offset can't hide the whole row
+            let mut state = TableState::new().with_line_offset(5);
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 2, 4), &mut buf, &mut state);
+            assert_eq!(state.line_offset(), 1);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["  ", "R1", "  ", "  "]));
+
+            let mut state = TableState::new().with_line_offset(1).with_selected(5);
+            StatefulWidget::render(table, Rect::new(0, 0, 2, 4), &mut buf, &mut state);
+            assert_eq!(state.line_offset(), 0);
+            assert_eq!(state.visible_rows(), (4, 6));
+        }
+
+        #[test]
+        fn render_with_centered_selection() {
+            let rows = (0..20).map(|i| Row::new(vec![format!("Row{i:02}")]));
+            let table = Table::new(rows, [Constraint::Length(5)]).highlight_symbol(">");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+            let mut state = TableState::new().with_selected(10);
+            state.set_scroll_mode(ScrollMode::CenterSelection);
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            let expected =
+                Buffer::with_lines(vec![" Row08", " Row09", ">Row10", " Row11", " Row12"]);
+            assert_buffer_eq!(buf, expected);
+
+            // the selected row stays centered while the selection moves
+            state.select(Some(11));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.visible_rows(), (9, 14));
+
+            // near the ends of the data, the rows are not scrolled past the first and last rows
+            state.select(Some(1));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.visible_rows(), (0, 5));
+            state.select(Some(18));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.visible_rows(), (15, 20));
+        }
+
+        #[test]
+        fn render_take_visible_change() {
+            let rows = (0..10).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.take_visible_change(), Some((0, 3)));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.take_visible_change(), None);
+            state.select(Some(4));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.take_visible_change(), Some((2, 5)));
+        }
+
+        #[test]
+        fn render_with_overflow_markers() {
+            let rows = (0..10).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(6)])
+                .overflow_markers(true)
+                .overflow_marker_style(Style::new().dark_gray());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+            let mut state = TableState::new();
+            state.scroll_to(4);
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 6, 5), &mut buf, &mut state);
+            let mut expected =
+                Buffer::with_lines(vec!["▲ more", "Row4  ", "Row5  ", "Row6  ", "▼ more"]);
+            expected.set_style(Rect::new(0, 0, 6, 1), Style::new().dark_gray());
+            expected.set_style(Rect::new(0, 4, 6, 1), Style::new().dark_gray());
+            assert_buffer_eq!(buf, expected);
+            assert_eq!(state.visible_rows(), (4, 7));
+            let visible = table.visible_rows(Rect::new(0, 0, 6, 5), &state);
+            assert_eq!(visible.map(|(i, _)| i).collect::<Vec<_>>(), [4, 5, 6]);
+
+            // no marker is displayed at the ends of the rows
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+            let mut state = TableState::new();
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 6, 5), &mut buf, &mut state);
+            assert_eq!(buf.get(0, 0).symbol(), "R");
+            assert_eq!(buf.get(0, 4).symbol(), "▼");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+            let mut state = TableState::new().with_selected(9);
+            StatefulWidget::render(table, Rect::new(0, 0, 6, 5), &mut buf, &mut state);
+            assert_eq!(buf.get(0, 0).symbol(), "▲");
+            assert_eq!(buf.get(0, 4).symbol(), "R");
+        }
+
+        #[test]
+        fn render_with_empty_fill_anchored_at_bottom_and_caption_below_rows() {
+            let table = Table::new(vec![Row::new(vec!["Cell1"])], [Constraint::Length(5)])
+                .caption("1 row")
+                .empty_fill('·', Style::new());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+            Widget::render(
+                table.clone().anchor(Anchor::Bottom),
+                Rect::new(0, 0, 5, 4),
+                &mut buf,
+            );
+            let expected = Buffer::with_lines(vec!["·····", "·····", "Cell1", "1 row"]);
+            assert_buffer_eq!(buf, expected);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+            Widget::render(
+                table.caption_position(CaptionPosition::BelowRows),
+                Rect::new(0, 0, 5, 4),
+                &mut buf,
+            );
+            let expected = Buffer::with_lines(vec!["Cell1", "1 row", "·····", "·····"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_header_only() {
+            let table = Table::new(
+                vec![Row::new(vec!["Cell1", "Cell2", "Cell3"])],
+                [Constraint::Length(5); 3],
+            )
+            .header(Row::new(vec!["Head1", "Head2", "Head3"]))
+            .column_styles([Style::new().red(), Style::new().blue()]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+            table
+                .clone()
+                .render_header_only(Rect::new(0, 0, 20, 1), &mut buf, 0);
+            table.render_header_only(Rect::new(0, 1, 20, 1), &mut buf, 1);
+            let mut expected =
+                Buffer::with_lines(vec!["Head1 Head2 Head3   ", "Head2 Head3         "]);
+            expected.set_style(Rect::new(0, 0, 5, 1), Style::new().red());
+            expected.set_style(Rect::new(6, 0, 5, 1), Style::new().blue());
+            expected.set_style(Rect::new(0, 1, 5, 1), Style::new().blue());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_range() {
+            let rows = vec![
+                Row::new(vec!["A1", "B1", "C1", "D1"]),
+                Row::new(vec!["A2", "B2", "C2", "D2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(2); 4])
+                .header(Row::new(vec!["A", "B", "C", "D"]))
+                .highlight_style(Style::new().bold())
+                .column_range_style(Style::new().on_blue());
+            let mut state = TableState::new().with_selected(1);
+            state.set_column_range(Some((1, 2)));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec![
+                "A  B  C  D  ",
+                "A1 B1 C1 D1 ",
+                "A2 B2 C2 D2 ",
+                "            ",
+            ]);
+            expected.set_style(Rect::new(0, 2, 12, 1), Style::new().bold());
+            expected.set_style(Rect::new(3, 0, 2, 4), Style::new().on_blue());
+            expected.set_style(Rect::new(6, 0, 2, 4), Style::new().on_blue());
+            assert_buffer_eq!(buf, expected);
+
+            // the range refers to all the columns when the first columns are left out
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+            table.render_rows_only(buf.area, &mut buf, &mut state, 2);
+            let mut expected = Buffer::with_lines(vec!["C1 D1       ", "C2 D2       "]);
+            expected.set_style(Rect::new(0, 1, 12, 1), Style::new().bold());
+            expected.set_style(Rect::new(0, 0, 2, 2), Style::new().on_blue());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_rows_only() {
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .header(Row::new(vec!["Head1", "Head2", "Head3"]))
+                .caption("2 rows")
+                .highlight_symbol(">");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 3));
+            let mut state = TableState::new().with_selected(1);
+            table.render_rows_only(Rect::new(0, 0, 13, 3), &mut buf, &mut state, 1);
+            let expected =
+                Buffer::with_lines(vec![" Cell2 Cell3 ", ">Cell5 Cell6 ", "             "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_right_to_left() {
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .header(Row::new(vec![Cell::from("Head12").span(2), "Head3".into()]))
+                .highlight_symbol(">>");
+            let render = |table: Table| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+                let mut state = TableState::new().with_selected(1);
+                StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
+                buf
+            };
+            let expected = Buffer::with_lines(vec![
+                "  Head12      Head3 ",
+                "  Cell1 Cell2 Cell3 ",
+                ">>Cell4 Cell5 Cell6 ",
+            ]);
+            assert_buffer_eq!(render(table.clone()), expected);
+
+            let table = table.direction(HorizontalDirection::RightToLeft);
+            let expected = Buffer::with_lines(vec![
+                " Head3 Head12       ",
+                " Cell3 Cell2 Cell1  ",
+                " Cell6 Cell5 Cell4>>",
+            ]);
+            assert_buffer_eq!(render(table), expected);
+        }
+
+        #[test]
+        fn render_right_to_left_with_grid() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let rows = vec![Row::new(vec!["a", "b"])];
+            let table = Table::new(rows, [Constraint::Length(1); 2])
+                .direction(HorizontalDirection::RightToLeft)
+                .grid(BorderType::Plain);
+            Widget::render(table, Rect::new(0, 0, 7, 3), &mut buf);
+            let expected = Buffer::with_lines(vec!["┌───┬─┐", "│  b│a│", "└───┴─┘"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_edge_padding() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .edge_padding(2)
+                .highlight_symbol(">");
+            let mut state = TableState::new().with_selected(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 16, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec!["   Cell1 Cell2  ", ">  Cell3 Cell4  "]);
+            assert_buffer_eq!(buf, expected);
+            assert_eq!(state.last_column_rects()[0].x, 3);
+            assert_eq!(state.last_column_rects()[1], Rect::new(9, 0, 5, 2));
+        }
+
+        #[test]
+        fn render_with_invalid_selection() {
+            let render = |invalid_selection: InvalidSelection| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+                let rows = (1..=3).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)])
+                    .highlight_symbol(">>")
+                    .on_invalid_selection(invalid_selection);
+                let mut state = TableState::new().with_offset(1).with_selected(999);
+                StatefulWidget::render(table, Rect::new(0, 0, 7, 2), &mut buf, &mut state);
+                (buf, state)
+            };
+
+            let (buf, state) = render(InvalidSelection::Clamp);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["  Cell2", ">>Cell3"]));
+            assert_eq!(state.selected(), Some(2));
+
+            let (buf, state) = render(InvalidSelection::Clear);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Cell1  ", "Cell2  "]));
+            assert_eq!(state.selected(), None);
+
+            // the selection column is still reserved, but no row is highlighted
+            let (buf, state) = render(InvalidSelection::KeepOffset);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["  Cell2", "  Cell3"]));
+            assert_eq!(state.selected(), Some(999));
+            assert_eq!(state.offset(), 1);
+        }
+
+        #[test]
+        fn render_with_cell_style() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+            let rows = vec![
+                Row::new(vec!["Cell1"]),
+                Row::new(vec!["Cell2"]).style(Style::new().red()),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .block(Block::default().borders(Borders::ALL))
+                .style(Style::new().on_blue())
+                .cell_style(Style::new().yellow());
+            Widget::render(table, Rect::new(0, 0, 9, 5), &mut buf);
+            let mut expected = Buffer::with_lines(vec![
+                "┌───────┐",
+                "│Cell1  │",
+                "│Cell2  │",
+                "│       │",
+                "└───────┘",
+            ]);
+            // the base style paints the whole area, the cell style only the rows
+            expected.set_style(Rect::new(0, 0, 9, 5), Style::new().on_blue());
+            expected.set_style(Rect::new(1, 1, 7, 1), Style::new().yellow());
+            expected.set_style(Rect::new(1, 2, 7, 1), Style::new().red());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let header = Row::new(vec!["Head1", "Head2"]);
+            let rows = vec![
+                Row::new(vec![Cell::from("Cell1"), Cell::from("Cell2").blue()]),
+                Row::new(vec!["Cell3", "Cell4"]).style(Style::new().red().bold()),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(header)
+                .style(Style::new().on_black())
+                .column_styles([Style::new(), Style::new().green()]);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let mut expected = Buffer::with_lines(vec![
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+            ]);
+            expected.set_style(Rect::new(0, 0, 15, 3), Style::new().on_black());
+            expected.set_style(Rect::new(0, 2, 15, 1), Style::new().red().bold());
+            // the column style is layered above the row style...
+            expected.set_style(Rect::new(6, 0, 5, 3), Style::new().green());
+            // ...and beneath the cell style
+            expected.set_style(Rect::new(6, 1, 5, 1), Style::new().blue());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alternating_column_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 3));
+            let rows = vec![
+                Row::new(vec!["A1", "A2"]),
+                Row::new(vec!["B1", "B2"]).style(Style::new().on_red()),
+                Row::new(vec!["C1", "C2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .alternating_column_styles(Style::new().on_blue(), Style::new().on_green());
+            Widget::render(table, Rect::new(0, 0, 11, 3), &mut buf);
+            let mut expected =
+                Buffer::with_lines(vec!["A1    A2   ", "B1    B2   ", "C1    C2   "]);
+            expected.set_style(Rect::new(0, 0, 5, 3), Style::new().on_blue());
+            expected.set_style(Rect::new(6, 0, 5, 3), Style::new().on_green());
+            // the row style is patched over the bands
+            expected.set_style(Rect::new(0, 1, 11, 1), Style::new().on_red());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_header_separator() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+            let table = Table::new(
+                vec![Row::new(vec!["Cell1", "Cell2"])],
+                [Constraint::Length(5); 2],
+            )
+            .header(Row::new(vec!["Head1", "Head2"]).style(Style::new().bold()))
+            .header_separator('─');
+            Widget::render(table, Rect::new(0, 0, 11, 4), &mut buf);
+            let mut expected = Buffer::with_lines(vec![
+                "Head1 Head2",
+                "───────────",
+                "Cell1 Cell2",
+                "           ",
+            ]);
+            expected.set_style(Rect::new(0, 0, 11, 2), Style::new().bold());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_non_sticky_header() {
+            let render = |state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+                let rows = (1..=5).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)])
+                    .header(Row::new(vec!["Head1"]))
+                    .sticky_header(false);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, state);
+                buf
+            };
+
+            // the header is displayed when the table is not scrolled
+            let mut state = TableState::new().with_selected(1);
+            let expected = Buffer::with_lines(vec![
+                "Head1          ",
+                "Cell1          ",
+                "Cell2          ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+            assert_eq!(state.offset, 0);
+
+            // the header scrolls away with the first row
+            state.select(Some(2));
+            let expected = Buffer::with_lines(vec![
+                "Cell2          ",
+                "Cell3          ",
+                "Cell4          ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+            assert_eq!(state.offset, 1);
+
+            // and comes back when scrolling back to the top
+            state.select(Some(0));
+            let expected = Buffer::with_lines(vec![
+                "Head1          ",
+                "Cell1          ",
+                "Cell2          ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+            assert_eq!(state.offset, 0);
+        }
+
+        #[test]
+        fn render_with_pinned_top_rows() {
+            let render = |state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+                let rows = (1..=5).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)])
+                    .header(Row::new(vec!["Head1"]))
+                    .pinned_top_rows([Row::new(vec!["Total"])]);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 4), &mut buf, state);
+                buf
+            };
+
+            let mut state = TableState::new().with_selected(0);
+            let expected = Buffer::with_lines(vec![
+                "Head1          ",
+                "Total          ",
+                "Cell1          ",
+                "Cell2          ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+
+            // the pinned row stays put while the other rows scroll
+            state.select(Some(4));
+            let expected = Buffer::with_lines(vec![
+                "Head1          ",
+                "Total          ",
+                "Cell4          ",
+                "Cell5          ",
+            ]);
+            assert_buffer_eq!(render(&mut state), expected);
+            assert_eq!(state.offset, 3);
+        }
+
+        #[test]
+        fn render_with_pinned_top_rows_and_non_sticky_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = (1..=5).map(|i| Row::new(vec![format!("Cell{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .header(Row::new(vec!["Head1"]))
+                .pinned_top_rows([Row::new(vec!["Total"])])
+                .sticky_header(false);
+            let mut state = TableState::new().with_selected(4);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 4), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "Total          ",
+                "Cell3          ",
+                "Cell4          ",
+                "Cell5          ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_sticky_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = (1..=5).map(|i| Row::new(vec![format!("Cell{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)]).header(Row::new(vec!["Head1"]));
+            let mut state = TableState::new().with_selected(2);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "Head1          ",
+                "Cell2          ",
+                "Cell3          ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_reports_selection_changes() {
+            let render = |state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+                let rows = (1..=3).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)]);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, state);
+            };
+
+            // the selection is visible and within the rows
+            let mut state = TableState::new().with_selected(1);
+            render(&mut state);
+            assert!(!state.selection_changed_last_render());
+
+            // the selection is clamped to the last row
+            state.select(Some(10));
+            render(&mut state);
+            assert!(state.selection_changed_last_render());
+            assert_eq!(state.selected, Some(2));
+            assert_eq!(state.offset, 1);
+
+            // nothing changed since the last render
+            render(&mut state);
+            assert!(!state.selection_changed_last_render());
+
+            // the offset is moved to keep the selection visible
+            state.select(Some(0));
+            render(&mut state);
+            assert!(state.selection_changed_last_render());
+            assert_eq!(state.offset, 0);
+        }
+
+        #[test]
+        fn render_anchored_to_bottom() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![Row::new(vec!["Cell1"]), Row::new(vec!["Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(5)]).anchor(Anchor::Bottom);
+            Widget::render(table, Rect::new(0, 0, 15, 4), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "               ",
+                "               ",
+                "Cell1          ",
+                "Cell2          ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_anchored_to_bottom_follows_new_rows() {
+            let render = |count: usize, state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+                let rows = (1..=count).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)]).anchor(Anchor::Bottom);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, state);
+                buf
+            };
+            let mut state = TableState::new();
+            state.set_auto_follow(true);
+
+            let expected = Buffer::with_lines(vec![
+                "               ",
+                "Cell1          ",
+                "Cell2          ",
+            ]);
+            assert_buffer_eq!(render(2, &mut state), expected);
+
+            // new rows push the older ones up
+            let expected = Buffer::with_lines(vec![
+                "Cell3          ",
+                "Cell4          ",
+                "Cell5          ",
+            ]);
+            assert_buffer_eq!(render(5, &mut state), expected);
+            assert_eq!(state.offset, 2);
+
+            let expected = Buffer::with_lines(vec![
+                "Cell4          ",
+                "Cell5          ",
+                "Cell6          ",
+            ]);
+            assert_buffer_eq!(render(6, &mut state), expected);
+
+            // the view no longer follows the new rows once the user scrolled up
+            state.set_auto_follow(false);
+            state.select(Some(1));
+            let expected = Buffer::with_lines(vec![
+                "Cell2          ",
+                "Cell3          ",
+                "Cell4          ",
+            ]);
+            assert_buffer_eq!(render(7, &mut state), expected);
+        }
+
+        #[test]
+        fn render_clipped_with_wide_highlight_symbol() {
+            let mut buf = Buffer::with_lines(vec!["xxxxxxxxxx", "xxxxxxxxxx", "xxxxxxxxxx"]);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"]), Row::new(vec!["Cell3"])];
+            let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(5)])
+                .highlight_symbol(">>>>>>>>>>>>>>>>")
+                .selection_width_override(20);
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table.clone(), Rect::new(2, 1, 4, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec!["xxxxxxxxxx", "xx>>>>xxxx", "xxxxxxxxxx"]);
+            assert_buffer_eq!(buf, expected);
+
+            // the area is clamped to the buffer
+            let mut buf = Buffer::with_lines(vec!["xxxxxxxxxx", "xxxxxxxxxx", "xxxxxxxxxx"]);
+            table
+                .highlight_symbol("")
+                .render_clipped(Rect::new(6, 1, 10, 10), &mut buf);
+            let expected = Buffer::with_lines(vec!["xxxxxxxxxx", "xxxxxxCell", "xxxxxxCell"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_fills_viewport_when_rows_shrink() {
+            let render = |count: usize, state: &mut TableState| {
+                let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+                let rows = (1..=count).map(|i| Row::new(vec![format!("Cell{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)]);
+                StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, state);
+                buf
+            };
+            let mut state = TableState::new().with_offset(6).with_selected(8);
+            let expected = Buffer::with_lines(vec![
+                "Cell7          ",
+                "Cell8          ",
+                "Cell9          ",
+            ]);
+            assert_buffer_eq!(render(10, &mut state), expected);
+
+            // the stale offset is pulled up so that no blank rows are left at the bottom
+            let expected = Buffer::with_lines(vec![
+                "Cell2          ",
+                "Cell3          ",
+                "Cell4          ",
+            ]);
+            assert_buffer_eq!(render(4, &mut state), expected);
+            assert_eq!(state.offset, 1);
+
+            // rows that do not fill the viewport are still displayed from the top
+            let expected = Buffer::with_lines(vec![
+                "Cell1          ",
+                "Cell2          ",
+                "               ",
+            ]);
+            assert_buffer_eq!(render(2, &mut state), expected);
+            assert_eq!(state.offset, 0);
+        }
+
+        #[test]
+        fn render_auto_follow_moves_selection_to_last_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = (1..=4).map(|i| Row::new(vec![format!("Cell{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            let mut state = TableState::new().with_selected(0);
+            state.set_auto_follow(true);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            assert_eq!(state.selected, Some(3));
+            let expected = Buffer::with_lines(vec!["Cell3          ", "Cell4          "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_reuses_cached_columns_widths() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+            let table = Table::new(vec![Row::new(vec!["a", "b"])], [Constraint::Length(3); 2]);
+            let mut state = TableState::default();
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 20, 1), &mut buf, &mut state);
+            assert_eq!(state.columns_widths_cache.widths, [(0, 3), (4, 3)]);
+
+            // the widths computed by the previous render are reused at the same width
+            state.columns_widths_cache.widths = vec![(1, 3), (5, 3)];
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 20, 1), &mut buf, &mut state);
+            assert_eq!(state.last_column_rects()[0].x, 1);
+        }
+
+        #[test]
+        fn render_invalidates_cached_columns_widths() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+            let table = Table::new(
+                vec![Row::new(vec!["a", "b"])],
+                [Constraint::Percentage(50); 2],
+            );
+            let mut state = TableState::default();
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 20, 1), &mut buf, &mut state);
+            assert_eq!(state.columns_widths_cache.widths, [(0, 10), (11, 9)]);
+
+            StatefulWidget::render(table.clone(), Rect::new(0, 0, 10, 1), &mut buf, &mut state);
+            assert_eq!(state.columns_widths_cache.widths, [(0, 5), (6, 4)]);
+            assert_eq!(state.last_column_rects()[1], Rect::new(6, 0, 4, 1));
+
+            // the cache is not part of the state
+            let mut uncached = state.clone();
+            uncached.columns_widths_cache = ColumnsWidthsCache::default();
+            assert_eq!(state, uncached);
+        }
+
+        #[test]
+        fn render_records_column_rects() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .block(Block::default().borders(Borders::ALL));
+            let mut state = TableState::new();
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 6), &mut buf, &mut state);
+            // the rects are offset by the border and start below the header
+            assert_eq!(
+                state.last_column_rects(),
+                [Rect::new(1, 2, 5, 3), Rect::new(7, 2, 5, 3)]
+            );
+        }
+
+        #[test]
+        fn render_records_areas() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 8));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]).bottom_margin(1))
+                .caption(Line::from("Caption"))
+                .block(Block::default().borders(Borders::ALL));
+            let mut state = TableState::new();
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 8), &mut buf, &mut state);
+            // the areas are offset by the border, and the header includes its margin
+            assert_eq!(state.last_header_area(), Rect::new(1, 1, 13, 2));
+            assert_eq!(state.last_rows_area(), Rect::new(1, 3, 13, 3));
+            assert_eq!(state.last_caption_area(), Rect::new(1, 6, 13, 1));
+
+            // the header scrolled away is not recorded
+            let rows = (0..10).map(|i| Row::new(vec![i.to_string()]));
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .header(Row::new(vec!["Head"]))
+                .sticky_header(false);
+            let mut state = TableState::new().with_selected(9);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 4), &mut buf, &mut state);
+            assert_eq!(state.last_header_area(), Rect::default());
+            assert_eq!(state.last_rows_area(), Rect::new(0, 0, 15, 4));
+            assert_eq!(state.last_caption_area(), Rect::default());
+        }
+
+        #[test]
+        fn render_with_caption() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .caption(Line::from("3 rows").alignment(Alignment::Right));
+            Widget::render(table, Rect::new(0, 0, 15, 4), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+                "Cell5 Cell6    ",
+                "         3 rows",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_caption_below_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .caption("1 row")
+                .caption_position(CaptionPosition::BelowRows);
+            Widget::render(table, Rect::new(0, 0, 15, 5), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "1 row          ",
+                "               ",
+                "               ",
+                "               ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_caption_below_rows_and_grid() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 7));
+            let rows = vec![Row::new(vec!["a", "b"]), Row::new(vec!["c", "d"])];
+            let table = Table::new(rows, [Constraint::Length(1); 2])
+                .caption("2 rows")
+                .caption_position(CaptionPosition::BelowRows)
+                .grid(BorderType::Plain);
+            Widget::render(table, Rect::new(0, 0, 7, 7), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "┌─┬───┐",
+                "│a│b  │",
+                "├─┼───┤",
+                "│c│d  │",
+                "└─┴───┘",
+                "2 rows ",
+                "       ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_caption_reserves_a_line() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .caption("caption");
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "caption        ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_header_column_span() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            let header = Row::new(vec![Cell::from("Spanning group").span(2), Cell::from("H3")]);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2", "Cell3"])];
+            let table = Table::new(rows, [Constraint::Length(5); 3]).header(header);
+            Widget::render(table, Rect::new(0, 0, 17, 2), &mut buf);
+            let expected = Buffer::with_lines(vec!["Spanning gr H3   ", "Cell1 Cell2 Cell3"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_header_margin() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let header = Row::new(vec!["Head1", "Head2"]).bottom_margin(1);
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]).header(header);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Head1 Head2    ",
+                "               ",
+                "Cell1 Cell2    ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_margin() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]).bottom_margin(1),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "               ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_auto_height() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1\nline2\nline3", "Cell2"]).auto_height(),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            Widget::render(table, Rect::new(0, 0, 15, 4), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "line2          ",
+                "line3          ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let header = Row::new(vec!["Id", "Long description"])
+                .wrap(true)
+                .height(2);
+            let rows = vec![Row::new(vec!["1", "First"])];
+            let table =
+                Table::new(rows, [Constraint::Length(2), Constraint::Length(11)]).header(header);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Id Long        ",
+                "   description ",
+                "1  First       ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_auto_height_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+            let header = Row::new(vec!["Id", "A long label"])
+                .wrap(true)
+                .auto_height();
+            let rows = vec![Row::new(vec!["1", "First"])];
+            let table =
+                Table::new(rows, [Constraint::Length(2), Constraint::Length(6)]).header(header);
+            Widget::render(table, Rect::new(0, 0, 10, 4), &mut buf);
+            let expected =
+                Buffer::with_lines(vec!["Id A long ", "   label  ", "1  First  ", "          "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_vertical_alignment() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let header = Row::new(vec!["Group\nHead1", "Head2"])
+                .height(3)
+                .vertical_alignment(VerticalAlignment::Bottom);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])
+                .height(3)
+                .vertical_alignment(VerticalAlignment::Center)];
+            let table = Table::new(rows, [Constraint::Length(5); 2]).header(header);
+            Widget::render(table, Rect::new(0, 0, 15, 6), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "               ",
+                "Group          ",
+                "Head1 Head2    ",
+                "               ",
+                "Cell1 Cell2    ",
+                "               ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alignment() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec![Line::from("Left").alignment(Alignment::Left)]),
+                Row::new(vec![Line::from("Center").alignment(Alignment::Center)]),
+                Row::new(vec![Line::from("Right").alignment(Alignment::Right)]),
+            ];
+            let table = Table::new(rows, [Percentage(100)]);
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Left           ",
+                "    Center     ",
+                "          Right",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_overflow_does_not_panic() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+            let table = Table::new(vec![], [Constraint::Min(20); 1])
+                .header(Row::new([Line::from("").alignment(Alignment::Right)]));
+            Widget::render(table, Rect::new(0, 0, 20, 3), &mut buf);
+        }
+
+        #[test]
+        fn render_with_selected() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_style(Style::new().red())
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                ">>Cell1 Cell2  ".red(),
+                "  Cell3 Cell4  ".into(),
+                "               ".into(),
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_widths_override() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Section one"]).widths_override([Constraint::Percentage(100)]),
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Section two"]).widths_override([Constraint::Percentage(100)]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            Widget::render(table, Rect::new(0, 0, 15, 4), &mut buf);
+            let expected = Buffer::with_lines(vec![
+                "Section one    ",
+                "Cell1 Cell2    ",
+                "Section two    ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_decorator() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let rows = (0..4).map(|i| Row::new(vec![format!("Row{i}")]).red());
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .row_decorator(|index, _| {
+                    if index % 2 == 0 {
+                        Style::new().dim()
+                    } else {
+                        Style::new()
+                    }
+                })
+                .highlight_style(Style::new().blue());
+            let mut state = TableState::new().with_selected(2);
+            StatefulWidget::render(table, Rect::new(0, 0, 4, 4), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "Row0".red().dim(),
+                "Row1".red(),
+                "Row2".blue().dim(),
+                "Row3".red(),
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn row_decorator_equality() {
+            let table = Table::default().row_decorator(|_, _| Style::new());
+            assert_eq!(table.clone(), table);
+            assert_ne!(table, Table::default().row_decorator(|_, _| Style::new()));
+        }
+
+        #[test]
+        fn render_incremental_only_draws_dirty_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let mut state = TableState::default();
+            let table = |suffix: &str| {
+                let rows = (0..3).map(|i| Row::new(vec![format!("Row{i}{suffix}")]));
+                Table::new(rows, [Constraint::Length(5)]).incremental(true)
+            };
+            StatefulWidget::render(table("a"), buf.area, &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Row0a", "Row1a", "Row2a"]));
+
+            state.mark_row_dirty(1);
+            StatefulWidget::render(table("b"), buf.area, &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Row0a", "Row1b", "Row2a"]));
 
-        let vec = vec![Constraint::Percentage(100)];
-        let slice = vec.as_slice();
-        let table = Table::new(vec![], slice);
-        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "slice");
+            // nothing is dirty anymore
+            StatefulWidget::render(table("c"), buf.area, &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Row0a", "Row1b", "Row2a"]));
 
-        let vec = vec![Constraint::Percentage(100)];
-        let table = Table::new(vec![], vec);
-        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "vec");
+            state.mark_all_dirty();
+            StatefulWidget::render(table("d"), buf.area, &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["Row0d", "Row1d", "Row2d"]));
+        }
 
-        let vec_ref = &vec![Constraint::Percentage(100)];
-        let table = Table::new(vec![], vec_ref);
-        assert_eq!(table.widths, vec![Constraint::Percentage(100)], "vec ref");
-    }
+        #[test]
+        fn render_incremental_draws_all_rows_when_selection_changes() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let mut state = TableState::default();
+            let table = |suffix: &str| {
+                let rows = (0..2).map(|i| Row::new(vec![format!("Row{i}{suffix}")]));
+                Table::new(rows, [Constraint::Length(5)])
+                    .highlight_style(Style::new().red())
+                    .incremental(true)
+            };
+            StatefulWidget::render(table("a"), buf.area, &mut buf, &mut state);
+            state.select(Some(1));
+            StatefulWidget::render(table("b"), buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec!["Row0b".into(), "Row1b".red()]);
+            assert_buffer_eq!(buf, expected);
+        }
 
-    #[cfg(test)]
-    mod render {
-        use super::*;
-        use crate::{assert_buffer_eq, widgets::Borders};
+        #[test]
+        fn render_with_expanded_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+            let rows = (0..3).map(|i| {
+                Row::new(vec![format!("Row{i}")]).detail(format!("detail{i}\nmore{i}\nhidden"))
+            });
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .highlight_symbol(">>")
+                .highlight_style(Style::new().red())
+                .expanded_row_height(|index| (index == 1).then_some(3));
+            let mut state = TableState::new().with_selected(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "  Row0  ".into(),
+                ">>Row1  ".red(),
+                "  detail".red(),
+                "  more1 ".red(),
+                "  Row2  ".into(),
+                "        ".into(),
+            ]);
+            assert_buffer_eq!(buf, expected);
+        }
 
         #[test]
-        fn render_empty_area() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
-            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
-            let table = Table::new(rows, vec![Constraint::Length(5); 2]);
-            Widget::render(table, Rect::new(0, 0, 0, 0), &mut buf);
-            assert_buffer_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
+        fn expanded_row_scrolls_into_view() {
+            let rows = (0..4).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .expanded_row_height(|index| (index == 3).then_some(3));
+            let mut state = TableState::new().with_selected(3);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 2);
+            assert_eq!(state.visible_rows(), (2, 4));
         }
 
         #[test]
-        fn render_default() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
-            let table = Table::default();
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
-            assert_buffer_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
+        fn render_with_hovered_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+            let rows = (0..3).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .hover_style(Style::new().on_blue().italic())
+                .highlight_style(Style::new().red());
+            let mut state = TableState::new().with_selected(2);
+            state.set_hovered(Some(1));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            let expected =
+                Buffer::with_lines(vec!["Row0".into(), "Row1".on_blue().italic(), "Row2".red()]);
+            assert_buffer_eq!(buf, expected);
+
+            // the highlight style is layered on top of the hover style
+            state.set_hovered(Some(2));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![
+                "Row0".into(),
+                "Row1".into(),
+                "Row2".red().on_blue().italic(),
+            ]);
+            assert_buffer_eq!(buf, expected);
         }
 
         #[test]
-        fn render_with_block() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
-            let rows = vec![
-                Row::new(vec!["Cell1", "Cell2"]),
-                Row::new(vec!["Cell3", "Cell4"]),
-            ];
-            let block = Block::new().borders(Borders::ALL).title("Block");
-            let table = Table::new(rows, vec![Constraint::Length(5); 2]).block(block);
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+        fn render_with_selection_range() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+            let rows = (0..5).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .highlight_style(Style::new().red())
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(3);
+            state.set_selection_anchor(Some(1));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
             let expected = Buffer::with_lines(vec![
-                "┌Block────────┐",
-                "│Cell1 Cell2  │",
-                "└─────────────┘",
+                "  Row0".into(),
+                "  Row1".red(),
+                "  Row2".red(),
+                ">>Row3".red(),
+                "  Row4".into(),
             ]);
             assert_buffer_eq!(buf, expected);
         }
 
+        /// Renders a table of `count` single line rows into an area of the given height and
+        /// returns the resulting offset of the state.
+        fn rendered_offset(count: usize, height: u16, state: &mut TableState) -> usize {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, height));
+            let rows = (0..count).map(|i| Row::new(vec![i.to_string()]));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            StatefulWidget::render(table, buf.area, &mut buf, state);
+            state.offset()
+        }
+
         #[test]
-        fn render_with_header() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
-            let header = Row::new(vec!["Head1", "Head2"]);
+        fn render_with_scroll_padding_scrolling_down() {
+            let mut state = TableState::new().with_selected(10);
+            state.set_scroll_padding(2);
+            // rows 10 to 12 must be visible, so the viewport shows rows 8 to 12
+            assert_eq!(rendered_offset(20, 5, &mut state), 8);
+        }
+
+        #[test]
+        fn render_with_scroll_padding_scrolling_up() {
+            let mut state = TableState::new().with_offset(10).with_selected(10);
+            state.set_scroll_padding(2);
+            // rows 8 to 10 must be visible, so the viewport starts two rows above the selection
+            assert_eq!(rendered_offset(20, 5, &mut state), 8);
+        }
+
+        #[test]
+        fn render_with_scroll_padding_at_the_edges() {
+            let mut state = TableState::new().with_selected(0);
+            state.set_scroll_padding(2);
+            assert_eq!(rendered_offset(20, 5, &mut state), 0);
+
+            state.select(Some(19));
+            assert_eq!(rendered_offset(20, 5, &mut state), 15);
+        }
+
+        #[test]
+        fn render_with_scroll_padding_larger_than_area() {
+            let mut state = TableState::new().with_selected(10);
+            state.set_scroll_padding(5);
+            // the padding is reduced to 1 so that rows 9 to 11 fit in the area
+            assert_eq!(rendered_offset(20, 3, &mut state), 9);
+        }
+
+        #[test]
+        fn render_with_highlight_symbol_style() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
             let rows = vec![
                 Row::new(vec!["Cell1", "Cell2"]),
                 Row::new(vec!["Cell3", "Cell4"]),
             ];
-            let table = Table::new(rows, [Constraint::Length(5); 2]).header(header);
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
-            let expected = Buffer::with_lines(vec![
-                "Head1 Head2    ",
-                "Cell1 Cell2    ",
-                "Cell3 Cell4    ",
-            ]);
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_style(Style::new().on_blue())
+                .highlight_symbol(">>")
+                .highlight_symbol_style(Style::new().yellow().on_red());
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            let mut expected =
+                Buffer::with_lines(vec![">>Cell1 Cell2  ".on_blue(), "  Cell3 Cell4  ".into()]);
+            expected.set_style(Rect::new(0, 0, 2, 1), Style::new().yellow().on_red());
             assert_buffer_eq!(buf, expected);
         }
 
         #[test]
-        fn render_with_header_margin() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
-            let header = Row::new(vec!["Head1", "Head2"]).bottom_margin(1);
+        fn render_highlight_symbol_with_highlight_style() {
             let rows = vec![
-                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell1", "Cell2"]).dim(),
                 Row::new(vec!["Cell3", "Cell4"]),
             ];
-            let table = Table::new(rows, [Constraint::Length(5); 2]).header(header);
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_style(Style::new().yellow())
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0);
+
+            // by default the symbol is drawn with the style of the row
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
             let expected = Buffer::with_lines(vec![
-                "Head1 Head2    ",
-                "               ",
-                "Cell1 Cell2    ",
+                ">>Cell1 Cell2  ".yellow().dim(),
+                "  Cell3 Cell4  ".into(),
+            ]);
+            assert_buffer_eq!(buf, expected);
+
+            let table = table.highlight_symbol_use_highlight_style(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec![
+                ">>Cell1 Cell2  ".yellow().dim(),
+                "  Cell3 Cell4  ".into(),
             ]);
+            expected.set_style(Rect::new(0, 0, 2, 1), Style::reset().yellow());
             assert_buffer_eq!(buf, expected);
         }
 
         #[test]
-        fn render_with_row_margin() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+        fn render_with_highlight_symbol_repeat() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
             let rows = vec![
-                Row::new(vec!["Cell1", "Cell2"]).bottom_margin(1),
+                Row::new(vec!["Cell1", "Cell2"]).height(3),
                 Row::new(vec!["Cell3", "Cell4"]),
             ];
-            let table = Table::new(rows, [Constraint::Length(5); 2]);
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_symbol(">>")
+                .highlight_symbol_repeat(true);
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 4), &mut buf, &mut state);
             let expected = Buffer::with_lines(vec![
-                "Cell1 Cell2    ",
-                "               ",
-                "Cell3 Cell4    ",
+                ">>Cell1 Cell2  ",
+                ">>             ",
+                ">>             ",
+                "  Cell3 Cell4  ",
             ]);
             assert_buffer_eq!(buf, expected);
         }
 
         #[test]
-        fn render_with_alignment() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+        fn render_with_selected_zero_height_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
             let rows = vec![
-                Row::new(vec![Line::from("Left").alignment(Alignment::Left)]),
-                Row::new(vec![Line::from("Center").alignment(Alignment::Center)]),
-                Row::new(vec![Line::from("Right").alignment(Alignment::Right)]),
+                Row::new(vec!["a"]),
+                Row::new(vec!["b"]),
+                Row::new(vec!["c"]),
+                Row::new(vec!["d"]).height(0),
             ];
-            let table = Table::new(rows, [Percentage(100)]);
-            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
-            let expected = Buffer::with_lines(vec![
-                "Left           ",
-                "    Center     ",
-                "          Right",
-            ]);
-            assert_buffer_eq!(buf, expected);
+            let table = Table::new(rows, [Constraint::Length(3)]).highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(Some(3));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_buffer_eq!(buf, Buffer::with_lines(vec!["  a  ", "  b  ", "  c  "]));
         }
 
         #[test]
-        fn render_with_overflow_does_not_panic() {
-            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
-            let table = Table::new(vec![], [Constraint::Min(20); 1])
-                .header(Row::new([Line::from("").alignment(Alignment::Right)]));
-            Widget::render(table, Rect::new(0, 0, 20, 3), &mut buf);
+        fn render_with_highlight_symbol_repeat_skips_margin() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let rows = vec![
+                Row::new(vec!["a"]).height(2).bottom_margin(1),
+                Row::new(vec!["b"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3)])
+                .highlight_symbol(">")
+                .highlight_symbol_repeat(true);
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 4, 4), &mut buf, &mut state);
+            assert_buffer_eq!(
+                buf,
+                Buffer::with_lines(vec![">a  ", ">   ", "    ", " b  "])
+            );
         }
 
         #[test]
-        fn render_with_selected() {
+        fn render_with_selection_width_override() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
             let rows = vec![
                 Row::new(vec!["Cell1", "Cell2"]),
                 Row::new(vec!["Cell3", "Cell4"]),
             ];
             let table = Table::new(rows, [Constraint::Length(5); 2])
-                .highlight_style(Style::new().red())
-                .highlight_symbol(">>");
+                .highlight_symbol(">")
+                .selection_width_override(2);
             let mut state = TableState::new().with_selected(0);
             StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
             let expected = Buffer::with_lines(vec![
-                ">>Cell1 Cell2  ".red(),
-                "  Cell3 Cell4  ".into(),
-                "               ".into(),
+                "> Cell1 Cell2  ",
+                "  Cell3 Cell4  ",
+                "               ",
             ]);
             assert_buffer_eq!(buf, expected);
         }
     }
 
+    mod row_bounds {
+        use super::*;
+
+        #[test]
+        fn empty_table() {
+            let table = Table::default();
+            assert_eq!(table.get_row_bounds(&table.rows, None, 0, 10, 0), (0, 0));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(3), 5, 10, 2), (0, 0));
+        }
+
+        #[test]
+        fn zero_height_rows() {
+            let rows = (0..10).map(|i| {
+                let height = if (2..8).contains(&i) { 0 } else { 1 };
+                Row::new(vec![i.to_string()]).height(height)
+            });
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            assert_eq!(table.get_row_bounds(&table.rows, None, 0, 2, 0), (0, 8));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(8), 0, 2, 0), (1, 9));
+            // the leading zero height rows are not visible
+            assert_eq!(table.get_row_bounds(&table.rows, Some(9), 0, 2, 0), (2, 10));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(0), 9, 2, 0), (0, 8));
+        }
+
+        #[test]
+        fn only_zero_height_rows() {
+            let rows = (0..5).map(|i| Row::new(vec![i.to_string()]).height(0));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            assert_eq!(table.get_row_bounds(&table.rows, Some(4), 0, 2, 0), (0, 5));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(0), 4, 0, 0), (0, 5));
+        }
+
+        #[test]
+        fn single_row() {
+            let table = Table::new(vec![Row::new(vec!["0"])], [Constraint::Length(5)]);
+            assert_eq!(table.get_row_bounds(&table.rows, None, 0, 1, 0), (0, 1));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(0), 0, 1, 0), (0, 1));
+            // the selection and the offset past the only row are clamped
+            assert_eq!(table.get_row_bounds(&table.rows, Some(1), 1, 1, 1), (0, 1));
+            assert_eq!(
+                table.get_row_bounds(&table.rows, Some(usize::MAX), usize::MAX, 1, 0),
+                (0, 1)
+            );
+            assert_eq!(
+                table.get_row_bounds(&table.rows, Some(0), 0, 0, usize::MAX),
+                (0, 1)
+            );
+        }
+
+        #[test]
+        fn rows_of_maximum_height() {
+            let rows = [1, u16::MAX, 1].map(|height| Row::new(vec!["row"]).height(height));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            // the heights of the rows add up past the maximum height without overflowing, and
+            // the rows that do not fit are not visible
+            for _ in 0..2 {
+                assert_eq!(
+                    table.get_row_bounds(&table.rows, None, 0, u16::MAX, 0),
+                    (0, 1)
+                );
+                assert_eq!(
+                    table.get_row_bounds(&table.rows, Some(1), 0, u16::MAX, 0),
+                    (1, 2)
+                );
+                assert_eq!(
+                    table.get_row_bounds(&table.rows, Some(2), 0, u16::MAX, 1),
+                    (2, 3)
+                );
+                assert_eq!(
+                    table.get_row_bounds(&table.rows, Some(0), 2, u16::MAX, 0),
+                    (0, 1)
+                );
+            }
+        }
+
+        #[test]
+        fn row_taller_than_area() {
+            let rows = (0..5).map(|i| Row::new(vec![i.to_string()]).height(3));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            assert_eq!(table.get_row_bounds(&table.rows, Some(3), 0, 2, 0), (3, 4));
+            assert_eq!(table.get_row_bounds(&table.rows, Some(1), 4, 2, 0), (1, 2));
+        }
+    }
+
     // test how constraints interact with table column width allocation
     mod column_widths {
         use super::*;
@@ -1003,6 +7026,7 @@ mod tests {
         /// Construct a a new table with the given constraints, available and selection widths and
         /// tests that the widths match the expected list of (x, width) tuples.
         #[track_caller]
+        #[allow(deprecated)]
         fn test(
             constraints: &[Constraint],
             segment_size: SegmentSize,
@@ -1016,6 +7040,54 @@ mod tests {
             assert_eq!(widths, expected);
         }
 
+        #[test]
+        fn edge_padding() {
+            let table = Table::new(vec![], [Length(4), Min(0)])
+                .flex(Flex::LastTakesRemainder)
+                .edge_padding(2);
+            // the last column stops before the trailing padding
+            assert_eq!(table.get_columns_widths(20, 0), [(2, 4), (7, 11)]);
+            assert_eq!(table.get_columns_widths(20, 3), [(5, 4), (10, 8)]);
+        }
+
+        #[test]
+        fn width_resolver() {
+            let equal_split = |available: u16, constraints: &[Constraint], spacing: u16| {
+                let count = constraints.len() as u16;
+                let width = available.saturating_sub(spacing * (count - 1)) / count;
+                (0..count)
+                    .map(|i| (i * (width + spacing), width))
+                    .collect_vec()
+            };
+            let table =
+                Table::new(vec![], [Length(2), Length(8), Length(2)]).width_resolver(equal_split);
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 6), (7, 6), (14, 6)]);
+            // the resolver lays out the columns after the selection column
+            assert_eq!(table.get_columns_widths(20, 2), [(2, 5), (8, 5), (14, 5)]);
+
+            // the widths cached by a table without resolver are not reused
+            let mut cache = ColumnsWidthsCache::default();
+            Table::new(vec![], [Length(2), Length(8), Length(2)])
+                .get_cached_columns_widths(20, 0, &mut cache);
+            let table = table.width_resolver(|_, _, _| vec![(0, 1), (1, 1), (2, 1)]);
+            assert_eq!(
+                table.get_cached_columns_widths(20, 0, &mut cache),
+                [(0, 1), (1, 1), (2, 1)]
+            );
+        }
+
+        #[test]
+        fn visible_columns() {
+            let table = Table::new(vec![], [Length(4), Length(4), Min(0)])
+                .flex(Flex::LastTakesRemainder)
+                .visible_columns([false, true, false]);
+            // the hidden columns are at the position of the next displayed column
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 0), (0, 20), (20, 0)]);
+
+            let table = table.visible_columns([true, false, true]);
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 0), (5, 15)]);
+        }
+
         #[test]
         fn length_constraint() {
             // without selection, more than needed width
@@ -1250,6 +7322,21 @@ mod tests {
             );
         }
 
+        #[test]
+        fn underconstrained_with_flex() {
+            let widths = [Min(10), Min(10), Min(1)];
+            let table = Table::new(vec![], widths).flex(Flex::None);
+            assert_eq!(
+                table.get_columns_widths(62, 0),
+                [(0, 10), (11, 10), (22, 1)]
+            );
+            let table = table.flex(Flex::LastTakesRemainder);
+            assert_eq!(
+                table.get_columns_widths(62, 0),
+                [(0, 10), (11, 10), (22, 40)]
+            );
+        }
+
         #[test]
         fn no_constraint_with_rows() {
             let table = Table::default()
@@ -1266,6 +7353,153 @@ mod tests {
             )
         }
 
+        #[test]
+        fn no_constraint_with_distributed_remainder() {
+            let table = Table::default()
+                .rows(vec![Row::new(vec!["a", "b", "c"])])
+                .flex(Flex::None)
+                .column_spacing(1);
+            // the 20 cells left by the spacers are divided in 3, and 2 of them are left over
+            assert_eq!(table.get_columns_widths(22, 0), &[(0, 6), (7, 6), (14, 6)]);
+            let table = table.distribute_remainder(true);
+            assert_eq!(table.get_columns_widths(22, 0), &[(0, 7), (8, 7), (16, 6)]);
+            // the hidden columns take no part of the remainder
+            let table = table.visible_columns([false, true, true]);
+            assert_eq!(
+                table.get_columns_widths(22, 0),
+                &[(0, 0), (0, 11), (12, 10)]
+            );
+        }
+
+        #[test]
+        fn no_constraint_with_overflowing_spacing() {
+            let table = Table::default()
+                .rows(vec![Row::new(vec![""; 5])])
+                .column_spacing(u16::MAX);
+            // the total spacing between the columns overflows a u16
+            let widths = table.get_columns_widths(100, 0);
+            assert_eq!(widths.len(), 5);
+            assert!(widths.iter().all(|&(_, width)| width == 0));
+        }
+
+        #[test]
+        fn min_width_for_spacing() {
+            let table = Table::new(vec![], [Length(5), Length(5)])
+                .column_spacing(2)
+                .min_width_for_spacing(20);
+            // narrow: the columns are adjacent
+            assert_eq!(table.get_columns_widths(15, 0), [(0, 5), (5, 5)]);
+            // wide: the spacing is applied
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 5), (7, 5)]);
+
+            // the equal widths fallback also reclaims the spacing
+            let table = Table::default()
+                .rows(vec![Row::new(vec!["a", "b"])])
+                .column_spacing(2)
+                .min_width_for_spacing(20);
+            assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)]);
+            assert_eq!(table.get_columns_widths(22, 0), [(0, 10), (12, 10)]);
+        }
+
+        #[test]
+        fn explain_widths() {
+            let constraints = [Length(5), Percentage(25), Min(10)];
+            let table = Table::new(vec![], constraints);
+            assert_eq!(
+                table.explain_widths(Rect::new(0, 0, 40, 1)),
+                [(Length(5), 5), (Percentage(25), 10), (Min(10), 10)]
+            );
+
+            // the Min column keeps its minimum width when there is not enough room
+            let explained = table.explain_widths(Rect::new(0, 0, 18, 1));
+            assert_eq!(explained[2], (Min(10), 10));
+
+            // the inset of the block and the selection column are taken into account
+            let table = table
+                .block(Block::default().borders(Borders::ALL))
+                .highlight_symbol(">>")
+                .highlight_spacing(HighlightSpacing::Always);
+            assert_eq!(
+                table.explain_widths(Rect::new(0, 0, 42, 3)),
+                [(Length(5), 5), (Percentage(25), 10), (Min(10), 10)]
+            );
+        }
+
+        #[test]
+        fn explain_widths_without_widths() {
+            let table = Table::default()
+                .rows(vec![Row::new(vec!["a", "b"])])
+                .column_spacing(0);
+            assert_eq!(
+                table.explain_widths(Rect::new(0, 0, 10, 1)),
+                [(Length(5), 5), (Length(5), 5)]
+            );
+        }
+
+        #[test]
+        fn collapsed_column() {
+            // the spacing after the collapsed middle column is removed so its neighbors are only
+            // separated by a single spacing
+            test(
+                &[Length(4), Max(0), Length(4)],
+                SegmentSize::None,
+                20,
+                0,
+                &[(0, 4), (5, 0), (5, 4)],
+            );
+
+            // a cramped width collapsing the middle column
+            test(
+                &[Length(4), Max(1), Length(4)],
+                SegmentSize::None,
+                10,
+                0,
+                &[(0, 4), (5, 0), (5, 4)],
+            );
+
+            // several collapsed columns in a row
+            test(
+                &[Length(4), Max(0), Max(0), Length(4)],
+                SegmentSize::None,
+                20,
+                0,
+                &[(0, 4), (5, 0), (5, 0), (5, 4)],
+            );
+
+            // a collapsed first column
+            test(
+                &[Max(0), Length(4)],
+                SegmentSize::None,
+                20,
+                3,
+                &[(3, 0), (3, 4)],
+            );
+        }
+
+        #[test]
+        fn min_column_width() {
+            let constraints = [Length(4), Length(4), Length(4)];
+            let table = Table::new(vec![], constraints).min_column_width(3);
+            // the floor has no effect when the columns are wide enough
+            assert_eq!(table.get_columns_widths(20, 0), &[(0, 4), (5, 4), (10, 4)]);
+            // the third column is dropped instead of being displayed as a sliver
+            assert_eq!(table.get_columns_widths(9, 0), &[(0, 4), (5, 4)]);
+            // the second column no longer fits once widened to the floor
+            assert_eq!(table.get_columns_widths(7, 0), &[(0, 4)]);
+            assert_eq!(table.get_columns_widths(7, 3), &[(3, 4)]);
+        }
+
+        #[test]
+        fn min_column_width_at_cramped_widths() {
+            let constraints = [Length(4), Length(4), Length(4)];
+            let table = Table::new(vec![], constraints).min_column_width(3);
+            for width in 0..20 {
+                for (_, column_width) in table.get_columns_widths(width, 0) {
+                    assert!(column_width >= 3, "width {width}: column of {column_width}");
+                }
+            }
+        }
+
         #[test]
         fn no_constraint_with_header() {
             let table = Table::default()
@@ -1,3 +1,13 @@
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    layout::{Constraint, Rect, SegmentSize},
+    widgets::{HorizontalDirection, ScrollMode},
+};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -41,13 +51,119 @@
 /// Note that if [`Table::widths`] is not called before rendering, the rendered columns will have
 /// equal width.
 ///
+/// The state is independent of the table it is rendered with, so an application displaying
+/// several tables in the same area (e.g. in tabs) can keep one state per table, e.g. in a
+/// `HashMap`, to preserve the offset and the selection of each table. See [`TableState::reset`] to
+/// go back to the default offset and selection.
+///
+/// The state also records what the last render drew, such as the visible rows and the areas of the
+/// columns, so cloning it copies these as well. Only the fields set by the application (the
+/// offsets, the selection, the hovered row, the scrolling options...) are compared and hashed.
+///
 /// [`Table`]: crate::widgets::Table
 /// [`Table::widths`]: crate::widgets::Table::widths
 /// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone)]
 pub struct TableState {
     pub(crate) offset: usize,
+    pub(crate) line_offset: u16,
     pub(crate) selected: Option<usize>,
+    pub(crate) selection_anchor: Option<usize>,
+    pub(crate) hovered: Option<usize>,
+    pub(crate) column_range: Option<(usize, usize)>,
+    pub(crate) scroll_padding: u16,
+    pub(crate) scroll_mode: ScrollMode,
+    pub(crate) visible_rows: (usize, usize),
+    /// Range of the visible rows returned by the last [`TableState::take_visible_change`]
+    pub(crate) taken_visible_rows: Option<(usize, usize)>,
+    pub(crate) selection_changed: bool,
+    pub(crate) column_rects: Vec<Rect>,
+    /// Areas of the header, the rows and the caption drawn by the last render
+    pub(crate) header_area: Rect,
+    pub(crate) rows_area: Rect,
+    pub(crate) caption_area: Rect,
+    pub(crate) auto_follow: bool,
+    /// Whether the rows were scrolled with [`TableState::scroll_to`], so that the selected row is
+    /// not scrolled into view until the selection changes
+    pub(crate) scrolled_away: bool,
+    /// Rows to draw on the next incremental render, `None` when all the rows must be drawn
+    pub(crate) dirty_rows: Option<BTreeSet<usize>>,
+    /// Area, selection and offset of the last incremental render
+    pub(crate) rendered_view: Option<(Rect, Option<usize>, usize, u16)>,
+    /// Widths of the columns computed by the last render
+    pub(crate) columns_widths_cache: ColumnsWidthsCache,
+}
+
+impl PartialEq for TableState {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.line_offset == other.line_offset
+            && self.selected == other.selected
+            && self.selection_anchor == other.selection_anchor
+            && self.hovered == other.hovered
+            && self.column_range == other.column_range
+            && self.scroll_padding == other.scroll_padding
+            && self.scroll_mode == other.scroll_mode
+            && self.auto_follow == other.auto_follow
+            && self.scrolled_away == other.scrolled_away
+    }
+}
+
+impl Eq for TableState {}
+
+impl Hash for TableState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.line_offset.hash(state);
+        self.selected.hash(state);
+        self.selection_anchor.hash(state);
+        self.hovered.hash(state);
+        self.column_range.hash(state);
+        self.scroll_padding.hash(state);
+        self.scroll_mode.hash(state);
+        self.auto_follow.hash(state);
+        self.scrolled_away.hash(state);
+    }
+}
+
+/// The inputs from which the widths of the columns of a [`Table`] are computed
+///
+/// [`Table`]: crate::widgets::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ColumnsWidthsKey {
+    pub(crate) constraints: Vec<Constraint>,
+    pub(crate) max_width: u16,
+    pub(crate) selection_width: u16,
+    pub(crate) column_spacing: u16,
+    pub(crate) edge_padding: u16,
+    pub(crate) min_column_width: u16,
+    pub(crate) segment_size: SegmentSize,
+    pub(crate) visible_columns: Vec<bool>,
+    pub(crate) direction: HorizontalDirection,
+}
+
+/// The `(x, width)` of the columns computed by the last render, reused by the next renders as long
+/// as they are computed from the same [`ColumnsWidthsKey`]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ColumnsWidthsCache {
+    pub(crate) key: Option<ColumnsWidthsKey>,
+    pub(crate) widths: Vec<(u16, u16)>,
+}
+
+impl ColumnsWidthsCache {
+    /// Returns the cached widths when they were computed from `key`, or computes them with
+    /// `compute` and caches them otherwise.
+    pub(crate) fn get_or_compute(
+        &mut self,
+        key: ColumnsWidthsKey,
+        compute: impl FnOnce(&[Constraint]) -> Vec<(u16, u16)>,
+    ) -> Vec<(u16, u16)> {
+        if self.key.as_ref() != Some(&key) {
+            self.widths = compute(&key.constraints);
+            self.key = Some(key);
+        }
+        self.widths.clone()
+    }
 }
 
 impl TableState {
@@ -79,6 +195,24 @@ impl TableState {
         self
     }
 
+    /// Sets the number of lines of the first displayed row scrolled above the table
+    ///
+    /// See [`TableState::line_offset`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_offset(1).with_line_offset(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_line_offset(mut self, line_offset: u16) -> Self {
+        self.line_offset = line_offset;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -124,6 +258,39 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Number of lines of the first displayed row scrolled above the table
+    ///
+    /// This scrolls the rows line by line rather than row by row: the row at the
+    /// [`TableState::offset`] is displayed without its first `line_offset` lines, which leaves
+    /// room for more rows below it. The line offset is clamped on render so that at least one line
+    /// of the row is displayed, and is reset to `0` when the rows are scrolled to another first
+    /// row, e.g. to show the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.line_offset(), 0);
+    /// ```
+    pub fn line_offset(&self) -> u16 {
+        self.line_offset
+    }
+
+    /// Mutable reference to the number of lines of the first displayed row scrolled above the
+    /// table
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// *state.line_offset_mut() = 1;
+    /// ```
+    pub fn line_offset_mut(&mut self) -> &mut u16 {
+        &mut self.line_offset
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -167,10 +334,451 @@ impl TableState {
     /// ```
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.scrolled_away = false;
         if index.is_none() {
             self.offset = 0;
+            self.line_offset = 0;
+        }
+    }
+
+    /// Scrolls the rows so that the row at `index` is displayed at the top, without selecting it
+    ///
+    /// The offset is set to `index` and is adjusted on the next render, e.g. so that the last rows
+    /// fill the table. The selected row is left unchanged and is no longer scrolled into view,
+    /// until another row is selected with [`TableState::select`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new().with_selected(0);
+    /// state.scroll_to(100);
+    /// assert_eq!(state.offset(), 100);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn scroll_to(&mut self, index: usize) {
+        self.offset = index;
+        self.line_offset = 0;
+        self.scrolled_away = true;
+    }
+
+    /// Resets the offset and the selection
+    ///
+    /// The table is displayed from its first row, without any selected row or selection range, as
+    /// with a new state. The settings of the state, such as the [`TableState::scroll_padding`] and
+    /// the [`TableState::auto_follow`], are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new().with_offset(10).with_selected(12);
+    /// state.reset();
+    /// assert_eq!(state.offset(), 0);
+    /// assert_eq!(state.selected(), None);
+    /// ```
+    pub fn reset(&mut self) {
+        self.offset = 0;
+        self.line_offset = 0;
+        self.selected = None;
+        self.selection_anchor = None;
+        self.scrolled_away = false;
+    }
+
+    /// Index of the row where the selection range starts
+    ///
+    /// Returns `None` if only the selected row is highlighted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.selection_anchor(), None);
+    /// ```
+    pub fn selection_anchor(&self) -> Option<usize> {
+        self.selection_anchor
+    }
+
+    /// Sets the index of the row where the selection range starts
+    ///
+    /// When both the anchor and the selected row are set, every row between them (inclusive) is
+    /// rendered with the highlight style, like a shift-click selection in a spreadsheet. The
+    /// highlight symbol is still only displayed in front of the selected row. Set to `None` to
+    /// only highlight the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default().with_selected(Some(3));
+    /// // highlight the rows 1 to 3
+    /// state.set_selection_anchor(Some(1));
+    /// ```
+    pub fn set_selection_anchor(&mut self, anchor: Option<usize>) {
+        if self.selection_anchor != anchor {
+            self.mark_all_dirty();
+        }
+        self.selection_anchor = anchor;
+    }
+
+    /// Index of the row under the mouse cursor
+    ///
+    /// Returns `None` if no row is hovered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.hovered(), None);
+    /// ```
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Sets the index of the row under the mouse cursor
+    ///
+    /// The hovered row is rendered with [`Table::hover_style`], beneath the highlight style when
+    /// it is also selected. This is independent of the selection, so hover feedback can coexist
+    /// with the keyboard selection. Set to `None` when the mouse leaves the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.set_hovered(Some(1));
+    /// ```
+    ///
+    /// [`Table::hover_style`]: crate::widgets::Table::hover_style
+    pub fn set_hovered(&mut self, index: Option<usize>) {
+        if self.hovered != index {
+            self.mark_all_dirty();
+        }
+        self.hovered = index;
+    }
+
+    /// Range of the highlighted columns, as `(first, last)` inclusive indices
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_range(), None);
+    /// ```
+    pub fn column_range(&self) -> Option<(usize, usize)> {
+        self.column_range
+    }
+
+    /// Sets the range of the highlighted columns, as `(first, last)` inclusive indices
+    ///
+    /// Every column of the range is rendered with the [`Table::column_range_style`], in the header
+    /// and in the rows, e.g. to show the active group of columns of a pivot table. The indices
+    /// can be given in any order. Set to `None` to not highlight any column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new();
+    /// // highlight the columns 1 to 2
+    /// state.set_column_range(Some((1, 2)));
+    /// ```
+    ///
+    /// [`Table::column_range_style`]: crate::widgets::Table::column_range_style
+    pub fn set_column_range(&mut self, range: Option<(usize, usize)>) {
+        if self.column_range != range {
+            self.mark_all_dirty();
+        }
+        self.column_range = range;
+    }
+
+    /// Returns whether the column at `index` is part of the highlighted column range.
+    pub(crate) fn is_column_in_range(&self, index: usize) -> bool {
+        self.column_range
+            .is_some_and(|(first, last)| (first.min(last)..=first.max(last)).contains(&index))
+    }
+
+    /// Returns whether the row at `index` is part of the selection range, or is the selected row
+    /// when there is no selection anchor.
+    pub(crate) fn is_in_selection(&self, index: usize) -> bool {
+        match (self.selection_anchor, self.selected) {
+            (Some(anchor), Some(selected)) => {
+                (anchor.min(selected)..=anchor.max(selected)).contains(&index)
+            }
+            (None, Some(selected)) => selected == index,
+            (_, None) => false,
+        }
+    }
+
+    /// Range of the rows drawn by the last render, as `(start, end)` indices
+    ///
+    /// The `start` index is inclusive and the `end` index is exclusive. Both are `0` before the
+    /// first render, or when no row was drawn. This is useful to limit mouse hit-testing to the
+    /// rows that are actually visible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.visible_rows(), (0, 0));
+    /// ```
+    pub fn visible_rows(&self) -> (usize, usize) {
+        self.visible_rows
+    }
+
+    /// Returns the [`TableState::visible_rows`] when they changed since the last call, or `None`
+    ///
+    /// This fires once per change of the rows drawn, rather than on every frame, e.g. to fetch the
+    /// data of the rows scrolled into view. The first call returns the current range, even before
+    /// the first render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new();
+    /// assert_eq!(state.take_visible_change(), Some((0, 0)));
+    /// assert_eq!(state.take_visible_change(), None);
+    /// ```
+    pub fn take_visible_change(&mut self) -> Option<(usize, usize)> {
+        if self.taken_visible_rows == Some(self.visible_rows) {
+            return None;
+        }
+        self.taken_visible_rows = Some(self.visible_rows);
+        self.taken_visible_rows
+    }
+
+    /// Screen area of each column of the rows drawn by the last render
+    ///
+    /// Each [`Rect`] spans the width of a column and the height of the area of the rows, in
+    /// absolute screen coordinates (i.e. including the inset of the block). This is useful to
+    /// build interactions on the columns, such as resize handles. The slice is empty before the
+    /// first render, or when the table had no room to be drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(state.last_column_rects().is_empty());
+    /// ```
+    pub fn last_column_rects(&self) -> &[Rect] {
+        &self.column_rects
+    }
+
+    /// Screen area of the header drawn by the last render
+    ///
+    /// The area is in absolute screen coordinates (i.e. including the inset of the block). This is
+    /// useful to draw decorations aligned with the header, such as an underline. The area is empty
+    /// before the first render, or when no header was drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(state.last_header_area().is_empty());
+    /// ```
+    pub fn last_header_area(&self) -> Rect {
+        self.header_area
+    }
+
+    /// Screen area of the rows drawn by the last render
+    ///
+    /// The area is in absolute screen coordinates and spans the whole space available to the
+    /// rows, including the part left empty below the last row. The area is empty before the first
+    /// render, or when the table had no room to be drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(state.last_rows_area().is_empty());
+    /// ```
+    pub fn last_rows_area(&self) -> Rect {
+        self.rows_area
+    }
+
+    /// Screen area of the caption drawn by the last render
+    ///
+    /// The area is in absolute screen coordinates. The area is empty before the first render, or
+    /// when no caption was drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(state.last_caption_area().is_empty());
+    /// ```
+    pub fn last_caption_area(&self) -> Rect {
+        self.caption_area
+    }
+
+    /// Whether the last render adjusted the selection
+    ///
+    /// This is `true` when the last render clamped the selected index to the last row (e.g. after
+    /// rows were removed), or moved the offset to keep the selected row visible. Applications can
+    /// use this to only refresh what depends on the selection when it effectively changed, as the
+    /// selection may be modified by the render in addition to the application.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(!state.selection_changed_last_render());
+    /// ```
+    pub fn selection_changed_last_render(&self) -> bool {
+        self.selection_changed
+    }
+
+    /// Whether the table follows the last row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert!(!state.auto_follow());
+    /// ```
+    pub fn auto_follow(&self) -> bool {
+        self.auto_follow
+    }
+
+    /// Sets whether the table follows the last row
+    ///
+    /// When enabled, the table is scrolled to keep the last row visible on each render, so that
+    /// new rows are displayed as they are added. If a row is selected, the selection is moved to
+    /// the last row too. Disable it when the user scrolls up to read older rows, and enable it
+    /// again when they come back to the end. This pairs well with [`Anchor::Bottom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.set_auto_follow(true);
+    /// ```
+    ///
+    /// [`Anchor::Bottom`]: crate::widgets::Anchor::Bottom
+    pub fn set_auto_follow(&mut self, auto_follow: bool) {
+        self.auto_follow = auto_follow;
+    }
+
+    /// Marks a row as changed so that it is drawn on the next render
+    ///
+    /// This is only relevant when the table is rendered with [`Table::incremental`], in which case
+    /// only the rows marked as dirty since the last render are drawn. The `index` is the index of
+    /// the row in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.mark_row_dirty(3);
+    /// ```
+    ///
+    /// [`Table::incremental`]: crate::widgets::Table::incremental
+    pub fn mark_row_dirty(&mut self, index: usize) {
+        if let Some(ref mut dirty_rows) = self.dirty_rows {
+            dirty_rows.insert(index);
         }
     }
+
+    /// Marks all the rows as changed so that they are all drawn on the next render
+    ///
+    /// This is only relevant when the table is rendered with [`Table::incremental`]. All the rows
+    /// are also drawn when the area, the selection or the offset of the table changed since the
+    /// last render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.mark_all_dirty();
+    /// ```
+    ///
+    /// [`Table::incremental`]: crate::widgets::Table::incremental
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty_rows = None;
+    }
+
+    /// Returns whether the row at `index` must be drawn on the next incremental render
+    pub(crate) fn is_row_dirty(&self, index: usize) -> bool {
+        self.dirty_rows
+            .as_ref()
+            .map_or(true, |dirty_rows| dirty_rows.contains(&index))
+    }
+
+    /// Number of rows kept visible above and below the selected row when scrolling
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.scroll_padding(), 0);
+    /// ```
+    pub fn scroll_padding(&self) -> u16 {
+        self.scroll_padding
+    }
+
+    /// Sets the number of rows to keep visible above and below the selected row
+    ///
+    /// Similar to vim's `scrolloff` option, the table starts scrolling before the selected row
+    /// reaches the edge of the viewport so that `padding` rows of context remain visible around
+    /// it. The padding shrinks at the very top and bottom of the data, and when the viewport is
+    /// too short to fit the selected row with the padding on both sides.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.set_scroll_padding(2);
+    /// ```
+    pub fn set_scroll_padding(&mut self, padding: u16) {
+        self.scroll_padding = padding;
+    }
+
+    /// How the rows are scrolled to show the selected row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.scroll_mode(), ScrollMode::KeepVisible);
+    /// ```
+    pub fn scroll_mode(&self) -> ScrollMode {
+        self.scroll_mode
+    }
+
+    /// Sets how the rows are scrolled to show the selected row
+    ///
+    /// With [`ScrollMode::CenterSelection`], the selected row stays in the middle of the table
+    /// while the rows scroll around it, e.g. for a cursor-centric view, except near the first and
+    /// last rows. This differs from the [`TableState::scroll_padding`], which only keeps a margin
+    /// around the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.set_scroll_mode(ScrollMode::CenterSelection);
+    /// ```
+    pub fn set_scroll_mode(&mut self, mode: ScrollMode) {
+        self.scroll_mode = mode;
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +798,16 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn line_offset() {
+        let mut state = TableState::new().with_offset(1).with_line_offset(2);
+        assert_eq!(state.line_offset(), 2);
+        *state.line_offset_mut() = 1;
+        assert_eq!(state.line_offset, 1);
+        state.scroll_to(3);
+        assert_eq!(state.line_offset, 0);
+    }
+
     #[test]
     fn with_selected() {
         let state = TableState::new().with_selected(Some(1));
@@ -229,10 +847,199 @@ mod tests {
         assert_eq!(state.selected, Some(1));
     }
 
+    #[test]
+    fn reset() {
+        let mut state = TableState::new().with_offset(10).with_selected(12);
+        state.set_selection_anchor(Some(11));
+        state.set_scroll_padding(2);
+        state.reset();
+        assert_eq!(state.offset, 0);
+        assert_eq!(state.selected, None);
+        assert_eq!(state.selection_anchor, None);
+        assert_eq!(state.scroll_padding, 2);
+    }
+
+    #[test]
+    fn eq_and_hash_ignore_render_outputs() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |state: &TableState| {
+            let mut hasher = DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        };
+        let state = TableState::new().with_offset(1).with_selected(2);
+        let mut rendered = state.clone();
+        rendered.visible_rows = (1, 4);
+        rendered.column_rects = vec![Rect::new(0, 0, 5, 1)];
+        rendered.rows_area = Rect::new(0, 0, 5, 3);
+        rendered.selection_changed = true;
+        rendered.dirty_rows = Some(BTreeSet::from([2]));
+        rendered.rendered_view = Some((Rect::new(0, 0, 5, 3), Some(2), 1, 0));
+        assert_eq!(rendered, state);
+        assert_eq!(hash(&rendered), hash(&state));
+
+        rendered.set_hovered(Some(3));
+        assert_ne!(rendered, state);
+    }
+
+    #[test]
+    fn scroll_to() {
+        let mut state = TableState::new().with_selected(2);
+        state.scroll_to(50);
+        assert_eq!(state.offset, 50);
+        assert_eq!(state.selected, Some(2));
+        assert!(state.scrolled_away);
+        state.select(Some(3));
+        assert!(!state.scrolled_away);
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let mut state = TableState::new().with_offset(1).with_selected(2);
+        let clone = state.clone();
+        state.select(Some(5));
+        *state.offset_mut() = 3;
+        assert_eq!(clone.offset(), 1);
+        assert_eq!(clone.selected(), Some(2));
+        state.reset();
+        assert_eq!(clone, TableState::new().with_offset(1).with_selected(2));
+    }
+
     #[test]
     fn select_none() {
         let mut state = TableState::new().with_selected(Some(1));
         state.select(None);
         assert_eq!(state.selected, None);
     }
+
+    #[test]
+    fn column_range() {
+        let mut state = TableState::new();
+        assert_eq!(state.column_range(), None);
+        assert!(!state.is_column_in_range(0));
+
+        state.set_column_range(Some((3, 1)));
+        assert_eq!(state.column_range(), Some((3, 1)));
+        assert!(!state.is_column_in_range(0));
+        assert!((1..=3).all(|index| state.is_column_in_range(index)));
+        assert!(!state.is_column_in_range(4));
+    }
+
+    #[test]
+    fn selection_anchor() {
+        let mut state = TableState::new().with_selected(Some(3));
+        assert_eq!(state.selection_anchor(), None);
+        assert!(state.is_in_selection(3));
+        assert!(!state.is_in_selection(2));
+
+        state.set_selection_anchor(Some(1));
+        assert_eq!(state.selection_anchor(), Some(1));
+        assert!(!state.is_in_selection(0));
+        assert!((1..=3).all(|index| state.is_in_selection(index)));
+        assert!(!state.is_in_selection(4));
+
+        // the anchor can be after the selected row
+        state.select(Some(0));
+        assert!((0..=1).all(|index| state.is_in_selection(index)));
+        assert!(!state.is_in_selection(2));
+
+        state.select(None);
+        assert!(!state.is_in_selection(1));
+    }
+
+    #[test]
+    fn hovered() {
+        let mut state = TableState::new();
+        assert_eq!(state.hovered(), None);
+        state.set_hovered(Some(1));
+        assert_eq!(state.hovered, Some(1));
+        assert_eq!(state.hovered(), Some(1));
+    }
+
+    #[test]
+    fn visible_rows() {
+        let state = TableState::new();
+        assert_eq!(state.visible_rows(), (0, 0));
+    }
+
+    #[test]
+    fn take_visible_change() {
+        let mut state = TableState::new();
+        state.visible_rows = (2, 5);
+        assert_eq!(state.take_visible_change(), Some((2, 5)));
+        assert_eq!(state.take_visible_change(), None);
+        state.visible_rows = (3, 6);
+        assert_eq!(state.take_visible_change(), Some((3, 6)));
+    }
+
+    #[test]
+    fn last_column_rects() {
+        let mut state = TableState::new();
+        assert!(state.last_column_rects().is_empty());
+        state.column_rects = vec![Rect::new(0, 0, 5, 3)];
+        assert_eq!(state.last_column_rects(), [Rect::new(0, 0, 5, 3)]);
+    }
+
+    #[test]
+    fn last_areas() {
+        let mut state = TableState::new();
+        assert_eq!(state.last_header_area(), Rect::default());
+        assert_eq!(state.last_rows_area(), Rect::default());
+        assert_eq!(state.last_caption_area(), Rect::default());
+        state.header_area = Rect::new(0, 0, 5, 1);
+        state.rows_area = Rect::new(0, 1, 5, 3);
+        state.caption_area = Rect::new(0, 4, 5, 1);
+        assert_eq!(state.last_header_area(), Rect::new(0, 0, 5, 1));
+        assert_eq!(state.last_rows_area(), Rect::new(0, 1, 5, 3));
+        assert_eq!(state.last_caption_area(), Rect::new(0, 4, 5, 1));
+    }
+
+    #[test]
+    fn selection_changed_last_render() {
+        let mut state = TableState::new();
+        assert!(!state.selection_changed_last_render());
+        state.selection_changed = true;
+        assert!(state.selection_changed_last_render());
+    }
+
+    #[test]
+    fn auto_follow() {
+        let mut state = TableState::new();
+        assert!(!state.auto_follow());
+        state.set_auto_follow(true);
+        assert!(state.auto_follow);
+        assert!(state.auto_follow());
+    }
+
+    #[test]
+    fn mark_row_dirty() {
+        let mut state = TableState::new();
+        // all the rows are dirty until the first incremental render
+        assert!(state.is_row_dirty(1));
+        state.dirty_rows = Some(BTreeSet::new());
+        assert!(!state.is_row_dirty(1));
+        state.mark_row_dirty(1);
+        assert!(state.is_row_dirty(1));
+        assert!(!state.is_row_dirty(2));
+        state.mark_all_dirty();
+        assert!(state.is_row_dirty(2));
+    }
+
+    #[test]
+    fn scroll_padding() {
+        let mut state = TableState::new();
+        assert_eq!(state.scroll_padding(), 0);
+        state.set_scroll_padding(2);
+        assert_eq!(state.scroll_padding, 2);
+        assert_eq!(state.scroll_padding(), 2);
+    }
+
+    #[test]
+    fn scroll_mode() {
+        let mut state = TableState::new();
+        assert_eq!(state.scroll_mode(), ScrollMode::KeepVisible);
+        state.set_scroll_mode(ScrollMode::CenterSelection);
+        assert_eq!(state.scroll_mode, ScrollMode::CenterSelection);
+    }
 }
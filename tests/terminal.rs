@@ -3,9 +3,10 @@ use std::error::Error;
 use ratatui::{
     assert_buffer_eq,
     backend::{Backend, TestBackend},
-    layout::Rect,
+    layout::{Constraint, Rect},
     prelude::Buffer,
-    widgets::{Paragraph, Widget},
+    style::Style,
+    widgets::{Paragraph, Row, Table, TableState, Widget},
     Terminal, TerminalOptions, Viewport,
 };
 
@@ -50,6 +51,105 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_last_flush_changed_when_frame_changes() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    assert!(terminal.last_flush_changed());
+
+    let draw = |terminal: &mut Terminal<TestBackend>, text: &'static str| {
+        terminal
+            .draw(|f| f.render_widget(Paragraph::new(text), f.size()))
+            .map(drop)
+    };
+    draw(&mut terminal, "Hello")?;
+    assert!(terminal.last_flush_changed());
+    draw(&mut terminal, "Hello")?;
+    assert!(!terminal.last_flush_changed());
+    draw(&mut terminal, "World")?;
+    assert!(terminal.last_flush_changed());
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_incremental_table_with_retained_buffer() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(5, 3);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_retain_buffer(true);
+    let mut state = TableState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut TableState, suffix: &str| {
+        let rows = (0..3).map(|i| Row::new(vec![format!("Row{i}{suffix}")]));
+        let table = Table::new(rows, [Constraint::Length(5)]).incremental(true);
+        terminal
+            .draw(|f| f.render_stateful_widget(table, f.size(), state))
+            .map(drop)
+    };
+    draw(&mut terminal, &mut state, "a")?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Row0a", "Row1a", "Row2a"]));
+
+    // only the dirty row is drawn, the other rows are kept from the previous frame
+    state.mark_row_dirty(1);
+    draw(&mut terminal, &mut state, "b")?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Row0a", "Row1b", "Row2a"]));
+
+    draw(&mut terminal, &mut state, "c")?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Row0a", "Row1b", "Row2a"]));
+
+    // all the rows are drawn again after a resize
+    terminal.backend_mut().resize(5, 2);
+    draw(&mut terminal, &mut state, "d")?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Row0d", "Row1d"]));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_does_not_resize_when_autoresize_is_disabled() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_autoresize(false);
+    terminal.backend_mut().resize(8, 8);
+    let frame = terminal.draw(|f| {
+        assert_eq!(f.size(), Rect::new(0, 0, 10, 10));
+    })?;
+    assert_eq!(frame.area, Rect::new(0, 0, 10, 10));
+
+    terminal.resize(Rect::new(0, 0, 8, 8))?;
+    let frame = terminal.draw(|f| {
+        assert_eq!(f.size(), Rect::new(0, 0, 8, 8));
+    })?;
+    assert_eq!(frame.area, Rect::new(0, 0, 8, 8));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_records_cursor_on_selected_row() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend)?;
+    let mut state = TableState::default().with_selected(Some(2));
+    terminal.draw(|f| {
+        let rows = ["one", "two", "three", "four"].map(|s| Row::new(vec![s]));
+        let table = Table::new(rows, [Constraint::Length(10)]);
+        let area = f.size();
+        f.render_stateful_widget(table, area, &mut state);
+        let selected = state.selected().unwrap() - state.offset();
+        f.set_cursor(area.x, area.y + selected as u16);
+    })?;
+    assert_eq!(terminal.backend().cursor(), (0, 2));
+    assert!(!terminal.backend().cursor_hidden());
+
+    terminal.draw(|_| {})?;
+    assert!(terminal.backend().cursor_hidden());
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -94,6 +194,139 @@ fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_viewport_area_follows_insert_before() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+    assert_eq!(terminal.viewport(), &Viewport::Inline(1));
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 20, 1));
+
+    terminal.insert_before(2, |_| {})?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 2, 20, 1));
+
+    // the viewport does not move past the bottom of the terminal
+    terminal.insert_before(5, |_| {})?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 4, 20, 1));
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_widget_moves_viewport() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+
+    terminal.insert_before_widget(
+        2,
+        Paragraph::new(vec![
+            "------ Line 1 ------".into(),
+            "------ Line 2 ------".into(),
+        ]),
+    )?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.size());
+    })?;
+
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "------ Line 1 ------",
+            "------ Line 2 ------",
+            "[---- Viewport ----]",
+            "                    ",
+            "                    ",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_with_moves_viewport_by_rendered_height() -> Result<(), Box<dyn Error>> {
+    // When the closure reports that it used fewer lines than the maximum height, the viewport
+    // should only move down by the number of lines that were actually used.
+
+    let backend = TestBackend::new(20, 6);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+
+    terminal.insert_before_with(4, |buf| {
+        Paragraph::new(vec![
+            "------ Line 1 ------".into(),
+            "------ Line 2 ------".into(),
+        ])
+        .render(buf.area, buf);
+        2
+    })?;
+    terminal.insert_before_with(4, |buf| {
+        Paragraph::new("------ Line 3 ------").render(buf.area, buf);
+        1
+    })?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.size());
+    })?;
+
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "------ Line 1 ------",
+            "------ Line 2 ------",
+            "------ Line 3 ------",
+            "[---- Viewport ----]",
+            "                    ",
+            "                    ",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_with_nothing_rendered() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+
+    terminal.insert_before_with(2, |_| 0)?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.size());
+    })?;
+
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "[---- Viewport ----]",
+            "                    ",
+            "                    ",
+        ])
+    );
+
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_scrolls_on_large_input() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert many
@@ -193,3 +426,177 @@ fn terminal_insert_before_scrolls_on_many_inserts() -> Result<(), Box<dyn Error>
 
     Ok(())
 }
+
+#[test]
+fn terminal_insert_before_taller_than_terminal() -> Result<(), Box<dyn Error>> {
+    // The lines of a buffer taller than the whole terminal scroll through the space above the
+    // viewport, leaving the last lines of the buffer visible right above the viewport.
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+
+    terminal.insert_before(12, |buf| {
+        let lines = (1..=12)
+            .map(|i| format!("------ Line {i:<2} -----").into())
+            .collect::<Vec<_>>();
+        Paragraph::new(lines).render(buf.area, buf);
+    })?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.size());
+    })?;
+
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "------ Line 9  -----",
+            "------ Line 10 -----",
+            "------ Line 11 -----",
+            "------ Line 12 -----",
+            "[---- Viewport ----]",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_with_full_height_viewport() -> Result<(), Box<dyn Error>> {
+    // There is no space above a viewport taking the whole terminal, so nothing is inserted.
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(3),
+        },
+    )?;
+
+    terminal.insert_before(2, |buf| {
+        Paragraph::new("------ Line 1 ------").render(buf.area, buf);
+    })?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.size());
+    })?;
+
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "[---- Viewport ----]",
+            "                    ",
+            "                    ",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn terminal_inline_auto_viewport_fits_table() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 12);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::InlineAuto { min: 1, max: 10 },
+        },
+    )?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 10, 1));
+
+    let draw_rows = |terminal: &mut Terminal<TestBackend>, count: usize| {
+        terminal
+            .draw(|f| {
+                let rows = (0..count).map(|i| Row::new(vec![format!("Row{i}")]));
+                let table = Table::new(rows, [Constraint::Length(5)]);
+                f.set_content_height(table.min_size(count).1);
+                f.render_widget(table, f.size());
+            })
+            .map(|_| ())
+    };
+
+    // the viewport grows to show the small table fully
+    draw_rows(&mut terminal, 2)?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 10, 2));
+    assert_buffer_eq!(
+        terminal.backend().buffer().clone(),
+        Buffer::with_lines(vec![
+            "Row0      ",
+            "Row1      ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+        ])
+    );
+
+    // the viewport is capped to its maximum height for a large table
+    draw_rows(&mut terminal, 20)?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 10, 10));
+    assert_eq!(terminal.backend().buffer().get(0, 9).symbol(), "R");
+
+    // the viewport shrinks and the lines it leaves are cleared
+    draw_rows(&mut terminal, 0)?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 10, 1));
+    assert_eq!(terminal.backend().buffer().get(0, 1).symbol(), " ");
+    Ok(())
+}
+
+#[test]
+fn terminal_inline_auto_viewport_grows_at_the_bottom() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 5);
+    backend.set_cursor(0, 4)?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::InlineAuto { min: 1, max: 10 },
+        },
+    )?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 4, 10, 1));
+
+    // the viewport moves up to fit in the terminal, and is capped to its height
+    terminal.draw(|f| {
+        f.set_content_height(3);
+        f.render_widget(Paragraph::new("a\nb\nc"), f.size());
+    })?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 2, 10, 3));
+    terminal.draw(|f| {
+        f.set_content_height(8);
+        f.set_cursor(1, 1);
+    })?;
+    assert_eq!(terminal.viewport_area(), Rect::new(0, 0, 10, 5));
+    // the cursor set above the rendered content is kept at the top of the viewport
+    assert_eq!(terminal.get_cursor()?, (1, 0));
+    Ok(())
+}
+
+#[test]
+fn terminal_flush_area_writes_only_the_area() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let buffer = terminal.current_buffer_mut();
+    buffer.set_string(0, 0, "AB", Style::new());
+    buffer.set_string(5, 1, "CD", Style::new());
+    terminal.flush_area(Rect::new(0, 0, 3, 1))?;
+    assert_eq!(terminal.backend().last_drawn_cells(), [(0, 0), (1, 0)]);
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["AB        ", "          "]));
+
+    terminal.flush()?;
+    assert_eq!(
+        terminal.backend().last_drawn_cells(),
+        [(0, 0), (1, 0), (5, 1), (6, 1)]
+    );
+    Ok(())
+}
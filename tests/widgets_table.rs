@@ -909,6 +909,39 @@ fn widgets_table_columns_dont_panic() {
     test_case(&mut state, table1.clone(), table1_width);
 }
 
+#[test]
+fn widgets_table_render_table_returns_visible_rows() {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TableState::default();
+    let rows = (0..20).map(|i| Row::new(vec![format!("Row{i:02}")]));
+    let table = Table::new(rows, [Constraint::Length(5)])
+        .header(Row::new(vec!["Head"]))
+        .block(Block::default().borders(Borders::TOP));
+
+    let mut visible_rows = (0, 0);
+    state.select(Some(9));
+    terminal
+        .draw(|f| visible_rows = f.render_table(table.clone(), f.size(), &mut state))
+        .unwrap();
+    // the block and the header leave room for 3 rows, ending with the selected one
+    assert_eq!(visible_rows, (7, 10));
+    assert_eq!(state.visible_rows(), (7, 10));
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "──────────",
+        "Head      ",
+        "Row07     ",
+        "Row08     ",
+        "Row09     ",
+    ]));
+
+    state.select(Some(0));
+    terminal
+        .draw(|f| visible_rows = f.render_table(table, f.size(), &mut state))
+        .unwrap();
+    assert_eq!(visible_rows, (0, 3));
+}
+
 #[test]
 fn widgets_table_should_clamp_offset_if_rows_are_removed() {
     let backend = TestBackend::new(30, 8);